@@ -0,0 +1,164 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Annotations telling the client how to use content and resources.
+//!
+//! [`Annotations`] can be attached to content blocks, resources and resource
+//! templates. They are hints: `audience` says who the object is meant for,
+//! `priority` ranks it (1 is most important), and `last_modified` says when
+//! it last changed. With the `timestamps` feature `last_modified` is a
+//! `chrono::DateTime<Utc>`; without it, the ISO 8601 string as sent.
+
+use crate::errors::McpError;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Point in time carried by `lastModified`
+#[cfg(feature = "timestamps")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// Point in time carried by `lastModified` (ISO 8601)
+#[cfg(not(feature = "timestamps"))]
+pub type Timestamp = String;
+
+/// Intended reader of annotated content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// The human user
+    User,
+    /// The model
+    Assistant,
+}
+
+/// Hints about who content is for and how important it is
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Annotations {
+    /// Intended readers; empty means everyone
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audience: Vec<Role>,
+    /// Importance from 0 (least) to 1 (most)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, deserialize_with = "deserialize_priority")]
+    pub priority: Option<f64>,
+    /// When the annotated object was last modified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastModified")]
+    pub last_modified: Option<Timestamp>,
+}
+
+impl Annotations {
+    /// Create empty annotations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict the audience to `roles`
+    pub fn for_audience(mut self, roles: impl IntoIterator<Item = Role>) -> Self {
+        self.audience = roles.into_iter().collect();
+        self
+    }
+
+    /// Set the priority, which must lie in `[0, 1]`
+    pub fn with_priority(mut self, priority: f64) -> Result<Self, McpError> {
+        check_priority(priority)?;
+        self.priority = Some(priority);
+        Ok(self)
+    }
+
+    /// Set the last modification time
+    pub fn with_last_modified(mut self, last_modified: Timestamp) -> Self {
+        self.last_modified = Some(last_modified);
+        self
+    }
+
+    /// Whether the annotated object is meant for `role`
+    pub fn is_for(&self, role: Role) -> bool {
+        self.audience.is_empty() || self.audience.contains(&role)
+    }
+
+    /// Fail if the priority lies outside `[0, 1]`
+    pub fn validate(&self) -> Result<(), McpError> {
+        self.priority.map_or(Ok(()), check_priority)
+    }
+}
+
+/// Whether an object with optional `annotations` is meant for `role`
+pub(crate) fn is_for(annotations: Option<&Annotations>, role: Role) -> bool {
+    annotations.map_or(true, |annotations| annotations.is_for(role))
+}
+
+fn check_priority(priority: f64) -> Result<(), McpError> {
+    if (0.0..=1.0).contains(&priority) {
+        Ok(())
+    } else {
+        Err(McpError::invalid_params(format!(
+            "Priority must be between 0 and 1, got {}",
+            priority
+        )))
+    }
+}
+
+fn deserialize_priority<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let priority = Option::<f64>::deserialize(deserializer)?;
+    if let Some(priority) = priority {
+        check_priority(priority).map_err(|e| serde::de::Error::custom(e.message))?;
+    }
+    Ok(priority)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_priority_range() {
+        assert!(Annotations::new().with_priority(0.0).is_ok());
+        assert!(Annotations::new().with_priority(1.0).is_ok());
+        assert!(Annotations::new().with_priority(1.5).is_err());
+        assert!(Annotations::new().with_priority(f64::NAN).is_err());
+
+        let parsed: Annotations = serde_json::from_value(json!({"priority": 0.5})).unwrap();
+        assert_eq!(parsed.priority, Some(0.5));
+        assert!(parsed.validate().is_ok());
+        assert!(serde_json::from_value::<Annotations>(json!({"priority": -0.1})).is_err());
+    }
+
+    #[test]
+    fn test_audience() {
+        let everyone = Annotations::new();
+        assert!(everyone.is_for(Role::User) && everyone.is_for(Role::Assistant));
+
+        let user_only = Annotations::new().for_audience([Role::User]);
+        assert!(user_only.is_for(Role::User));
+        assert!(!user_only.is_for(Role::Assistant));
+        assert_eq!(
+            serde_json::to_value(&user_only).unwrap(),
+            json!({"audience": ["user"]})
+        );
+        assert!(is_for(None, Role::Assistant));
+    }
+
+    #[test]
+    fn test_last_modified() {
+        let json = json!({"lastModified": "2025-01-12T15:00:58Z"});
+        let parsed: Annotations = serde_json::from_value(json).unwrap();
+        #[cfg(feature = "timestamps")]
+        assert_eq!(
+            parsed.last_modified.unwrap().to_rfc3339(),
+            "2025-01-12T15:00:58+00:00"
+        );
+        #[cfg(not(feature = "timestamps"))]
+        assert_eq!(
+            parsed.last_modified.as_deref(),
+            Some("2025-01-12T15:00:58Z")
+        );
+        assert_eq!(
+            serde_json::to_value(Annotations::default()).unwrap(),
+            json!({})
+        );
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Request cancellation (`notifications/cancelled`).
+//!
+//! Either side may cancel a request it previously sent. Because the
+//! notification and the response can cross on the wire, the specification
+//! leaves several races to the implementations; this module settles them
+//! as follows:
+//!
+//! - A cancellation for an id that is unknown, or whose response was
+//!   already sent, is ignored by the receiver.
+//! - The receiver stops work on a cancelled request and sends no response.
+//! - The sender forgets the request when it cancels, and ignores any
+//!   response that arrives afterwards (see `PendingRequests::remove`).
+//! - The `initialize` request can never be cancelled.
+
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::pending::ExpiredRequest;
+use crate::protocol::{InitializeParams, JsonRpcRequest, McpNotification, McpRequest, RequestId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Notification that a previously sent request is cancelled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CancelledNotification {
+    /// Id of the request to cancel
+    #[serde(rename = "requestId")]
+    pub request_id: RequestId,
+    /// Optional human-readable reason
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Flag a request handler polls to learn whether its request was cancelled
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Requests currently being handled, and whether each has been cancelled
+#[derive(Debug, Default)]
+pub struct CancellationRegistry {
+    in_flight: HashMap<RequestId, CancellationToken>,
+}
+
+impl CancelledNotification {
+    /// Cancel a request this side sent
+    ///
+    /// Fails for `initialize`, which must not be cancelled.
+    pub fn for_request(request: &JsonRpcRequest, reason: Option<String>) -> Result<Self, McpError> {
+        Self::checked(&request.id, &request.method, reason)
+    }
+
+    /// Cancel a request that passed its deadline without a response
+    pub fn timed_out(expired: &ExpiredRequest) -> Result<Self, McpError> {
+        Self::checked(
+            &expired.id,
+            &expired.request.method,
+            Some("Request timed out".to_string()),
+        )
+    }
+
+    fn checked(id: &RequestId, method: &str, reason: Option<String>) -> Result<Self, McpError> {
+        if method == InitializeParams::METHOD {
+            return Err(McpError::invalid_request(
+                "The initialize request cannot be cancelled",
+            ));
+        }
+        Ok(Self {
+            request_id: id.clone(),
+            reason,
+            meta: None,
+        })
+    }
+}
+
+impl McpNotification for CancelledNotification {
+    const METHOD: &'static str = "notifications/cancelled";
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the request has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Mark the request as cancelled
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+}
+
+impl CancellationRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start tracking an incoming request, returning the token for its handler
+    ///
+    /// `initialize` is tracked like any other request, but cancellations
+    /// for it are ignored.
+    pub fn register(&mut self, request: &JsonRpcRequest) -> CancellationToken {
+        let token = CancellationToken::new();
+        if request.method != InitializeParams::METHOD {
+            self.in_flight.insert(request.id.clone(), token.clone());
+        }
+        token
+    }
+
+    /// Apply a received cancellation
+    ///
+    /// Returns `true` if a request still in flight was cancelled, `false` if
+    /// the id was unknown or already finished and the notification was ignored.
+    pub fn cancel(&mut self, notification: &CancelledNotification) -> bool {
+        match self.in_flight.get(&notification.request_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the request with `id` has been cancelled
+    pub fn is_cancelled(&self, id: &RequestId) -> bool {
+        self.in_flight
+            .get(id)
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Stop tracking a request whose handler has finished
+    ///
+    /// Returns `true` if its response should be sent, `false` if the request
+    /// was cancelled and the response must be dropped.
+    pub fn finish(&mut self, id: &RequestId) -> bool {
+        self.in_flight
+            .remove(id)
+            .map_or(true, |token| !token.is_cancelled())
+    }
+
+    /// Number of requests being tracked
+    pub fn len(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Whether no requests are being tracked
+    pub fn is_empty(&self) -> bool {
+        self.in_flight.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pending::PendingRequests;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_cancelled_notification_wire_format() {
+        let request = JsonRpcRequest::new(RequestId::Number(7), "tools/call");
+        let cancelled =
+            CancelledNotification::for_request(&request, Some("User aborted".to_string())).unwrap();
+        let notification = cancelled.clone().into_notification().unwrap();
+        assert_eq!(notification.method, "notifications/cancelled");
+        assert_eq!(
+            notification.params,
+            Some(json!({"requestId": 7, "reason": "User aborted"}))
+        );
+        assert_eq!(
+            CancelledNotification::from_notification(&notification).unwrap(),
+            cancelled
+        );
+
+        let initialize = JsonRpcRequest::new(RequestId::Number(1), "initialize");
+        assert!(CancelledNotification::for_request(&initialize, None).is_err());
+    }
+
+    #[test]
+    fn test_cancel_expired_request() {
+        let start = Instant::now();
+        let mut pending = PendingRequests::with_timeout(Duration::from_secs(1));
+        pending
+            .insert(
+                &JsonRpcRequest::new(RequestId::Number(3), "tools/call"),
+                start,
+            )
+            .unwrap();
+        let expired = pending.expire(start + Duration::from_secs(2));
+        let cancelled = CancelledNotification::timed_out(&expired[0]).unwrap();
+        assert_eq!(cancelled.request_id, RequestId::Number(3));
+    }
+
+    #[test]
+    fn test_registry_races() {
+        let mut registry = CancellationRegistry::new();
+        let request = JsonRpcRequest::new(RequestId::Number(1), "tools/call");
+        let token = registry.register(&request);
+        assert!(!token.is_cancelled());
+
+        let cancel = CancelledNotification {
+            request_id: RequestId::Number(1),
+            reason: None,
+            meta: None,
+        };
+        assert!(registry.cancel(&cancel));
+        assert!(token.is_cancelled());
+        assert!(registry.is_cancelled(&RequestId::Number(1)));
+        assert!(!registry.finish(&RequestId::Number(1)));
+
+        // Cancel arriving after the response was sent is ignored
+        let request = JsonRpcRequest::new(RequestId::Number(2), "tools/call");
+        registry.register(&request);
+        assert!(registry.finish(&RequestId::Number(2)));
+        let late = CancelledNotification {
+            request_id: RequestId::Number(2),
+            reason: None,
+            meta: None,
+        };
+        assert!(!registry.cancel(&late));
+
+        // initialize cannot be cancelled
+        let initialize = JsonRpcRequest::new(RequestId::Number(3), "initialize");
+        let token = registry.register(&initialize);
+        let cancel = CancelledNotification {
+            request_id: RequestId::Number(3),
+            reason: None,
+            meta: None,
+        };
+        assert!(!registry.cancel(&cancel));
+        assert!(!token.is_cancelled());
+        assert!(registry.is_empty());
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Capability queries and enforcement.
+//!
+//! Adds query helpers to [`ServerCapabilities`] and [`ClientCapabilities`],
+//! maps each method to the capability the receiving side must have
+//! advertised, and rejects messages for capabilities that were never
+//! negotiated.
+
+use crate::errors::{ErrorCode, McpError};
+use crate::protocol::{ClientCapabilities, ServerCapabilities, Side};
+use serde_json::json;
+use std::fmt;
+
+/// Individual capability a peer may advertise during initialization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Server offers tools
+    Tools,
+    /// Server notifies when its tool list changes
+    ToolsListChanged,
+    /// Server offers resources
+    Resources,
+    /// Server supports subscribing to resource updates
+    ResourcesSubscribe,
+    /// Server notifies when its resource list changes
+    ResourcesListChanged,
+    /// Server offers prompts
+    Prompts,
+    /// Server notifies when its prompt list changes
+    PromptsListChanged,
+    /// Server emits log messages
+    Logging,
+    /// Server completes prompt and resource template arguments
+    Completions,
+    /// Client exposes filesystem roots
+    Roots,
+    /// Client notifies when its roots change
+    RootsListChanged,
+    /// Client can sample from an LLM
+    Sampling,
+    /// Client can ask the user for input
+    Elicitation,
+}
+
+/// Side of the connection that advertises a capability
+///
+/// `Client` advertises in `InitializeParams.capabilities`, `Server` in
+/// `InitializeResult.capabilities`.
+pub type CapabilityOwner = Side;
+
+impl Capability {
+    /// Dotted path of the capability in the capabilities object
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Tools => "tools",
+            Self::ToolsListChanged => "tools.listChanged",
+            Self::Resources => "resources",
+            Self::ResourcesSubscribe => "resources.subscribe",
+            Self::ResourcesListChanged => "resources.listChanged",
+            Self::Prompts => "prompts",
+            Self::PromptsListChanged => "prompts.listChanged",
+            Self::Logging => "logging",
+            Self::Completions => "completions",
+            Self::Roots => "roots",
+            Self::RootsListChanged => "roots.listChanged",
+            Self::Sampling => "sampling",
+            Self::Elicitation => "elicitation",
+        }
+    }
+
+    /// Side of the connection that advertises this capability
+    pub fn owner(&self) -> CapabilityOwner {
+        match self {
+            Self::Roots | Self::RootsListChanged | Self::Sampling | Self::Elicitation => {
+                CapabilityOwner::Client
+            }
+            _ => CapabilityOwner::Server,
+        }
+    }
+
+    /// Capability required to send a request or notification with `method`
+    ///
+    /// Returns `None` for methods every peer must accept (e.g. `ping`,
+    /// `initialize`) and for methods this crate does not know.
+    pub fn required_for(method: &str) -> Option<Capability> {
+        match method {
+            "tools/list" | "tools/call" => Some(Self::Tools),
+            "notifications/tools/list_changed" => Some(Self::ToolsListChanged),
+            "resources/list" | "resources/read" | "resources/templates/list" => {
+                Some(Self::Resources)
+            }
+            "resources/subscribe" | "resources/unsubscribe" | "notifications/resources/updated" => {
+                Some(Self::ResourcesSubscribe)
+            }
+            "notifications/resources/list_changed" => Some(Self::ResourcesListChanged),
+            "prompts/list" | "prompts/get" => Some(Self::Prompts),
+            "notifications/prompts/list_changed" => Some(Self::PromptsListChanged),
+            "logging/setLevel" | "notifications/message" => Some(Self::Logging),
+            "completion/complete" => Some(Self::Completions),
+            "roots/list" => Some(Self::Roots),
+            "notifications/roots/list_changed" => Some(Self::RootsListChanged),
+            "sampling/createMessage" => Some(Self::Sampling),
+            "elicitation/create" => Some(Self::Elicitation),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl ServerCapabilities {
+    /// Whether the server offers tools
+    pub fn supports_tools(&self) -> bool {
+        self.tools.is_some()
+    }
+
+    /// Whether the server notifies when its tool list changes
+    pub fn supports_tools_list_changed(&self) -> bool {
+        self.tools
+            .as_ref()
+            .and_then(|tools| tools.list_changed)
+            .unwrap_or(false)
+    }
+
+    /// Whether the server offers resources
+    pub fn supports_resources(&self) -> bool {
+        self.resources.is_some()
+    }
+
+    /// Whether the server supports subscribing to resource updates
+    pub fn supports_resource_subscribe(&self) -> bool {
+        self.resources
+            .as_ref()
+            .and_then(|resources| resources.subscribe)
+            .unwrap_or(false)
+    }
+
+    /// Whether the server notifies when its resource list changes
+    pub fn supports_resources_list_changed(&self) -> bool {
+        self.resources
+            .as_ref()
+            .and_then(|resources| resources.list_changed)
+            .unwrap_or(false)
+    }
+
+    /// Whether the server offers prompts
+    pub fn supports_prompts(&self) -> bool {
+        self.prompts.is_some()
+    }
+
+    /// Whether the server notifies when its prompt list changes
+    pub fn supports_prompts_list_changed(&self) -> bool {
+        self.prompts
+            .as_ref()
+            .and_then(|prompts| prompts.list_changed)
+            .unwrap_or(false)
+    }
+
+    /// Whether the server emits log messages
+    pub fn supports_logging(&self) -> bool {
+        self.logging.is_some()
+    }
+
+    /// Whether the server completes prompt and resource template arguments
+    pub fn supports_completions(&self) -> bool {
+        self.completions.is_some()
+    }
+
+    /// Whether the server advertised `capability`
+    ///
+    /// Always `false` for capabilities owned by the client.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Tools => self.supports_tools(),
+            Capability::ToolsListChanged => self.supports_tools_list_changed(),
+            Capability::Resources => self.supports_resources(),
+            Capability::ResourcesSubscribe => self.supports_resource_subscribe(),
+            Capability::ResourcesListChanged => self.supports_resources_list_changed(),
+            Capability::Prompts => self.supports_prompts(),
+            Capability::PromptsListChanged => self.supports_prompts_list_changed(),
+            Capability::Logging => self.supports_logging(),
+            Capability::Completions => self.supports_completions(),
+            Capability::Roots
+            | Capability::RootsListChanged
+            | Capability::Sampling
+            | Capability::Elicitation => false,
+        }
+    }
+
+    /// Check that a message with `method` is backed by a server capability
+    ///
+    /// Methods requiring a client capability, or no capability at all, are
+    /// not the server's to refuse and pass this check.
+    pub fn check_allowed(&self, method: &str) -> Result<(), McpError> {
+        check(method, CapabilityOwner::Server, |capability| {
+            self.supports(capability)
+        })
+    }
+}
+
+impl ClientCapabilities {
+    /// Whether the client exposes filesystem roots
+    pub fn supports_roots(&self) -> bool {
+        self.roots.is_some()
+    }
+
+    /// Whether the client notifies when its roots change
+    pub fn supports_roots_list_changed(&self) -> bool {
+        self.roots
+            .as_ref()
+            .and_then(|roots| roots.list_changed)
+            .unwrap_or(false)
+    }
+
+    /// Whether the client can sample from an LLM
+    pub fn supports_sampling(&self) -> bool {
+        self.sampling.is_some()
+    }
+
+    /// Whether the client can ask the user for input
+    pub fn supports_elicitation(&self) -> bool {
+        self.elicitation.is_some()
+    }
+
+    /// Whether the client advertised `capability`
+    ///
+    /// Always `false` for capabilities owned by the server.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Roots => self.supports_roots(),
+            Capability::RootsListChanged => self.supports_roots_list_changed(),
+            Capability::Sampling => self.supports_sampling(),
+            Capability::Elicitation => self.supports_elicitation(),
+            _ => false,
+        }
+    }
+
+    /// Check that a message with `method` is backed by a client capability
+    ///
+    /// Methods requiring a server capability, or no capability at all, are
+    /// not the client's to refuse and pass this check.
+    pub fn check_allowed(&self, method: &str) -> Result<(), McpError> {
+        check(method, CapabilityOwner::Client, |capability| {
+            self.supports(capability)
+        })
+    }
+}
+
+/// Shared body of the `check_allowed` methods
+fn check(
+    method: &str,
+    owner: CapabilityOwner,
+    supports: impl Fn(Capability) -> bool,
+) -> Result<(), McpError> {
+    match Capability::required_for(method) {
+        Some(capability) if capability.owner() == owner && !supports(capability) => {
+            Err(McpError::with_data(
+                ErrorCode::MethodNotFound,
+                format!(
+                    "Capability {} was not advertised (required by {})",
+                    capability, method
+                ),
+                json!({
+                    "method": method,
+                    "capability": capability.name(),
+                }),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{ResourcesCapability, SamplingCapability, ToolsCapability};
+
+    #[test]
+    fn test_server_capability_queries() {
+        let caps = ServerCapabilities {
+            tools: Some(ToolsCapability { list_changed: None }),
+            resources: Some(ResourcesCapability {
+                subscribe: Some(true),
+                list_changed: Some(false),
+            }),
+            ..Default::default()
+        };
+        assert!(caps.supports_tools());
+        assert!(!caps.supports_tools_list_changed());
+        assert!(caps.supports_resource_subscribe());
+        assert!(!caps.supports_resources_list_changed());
+        assert!(!caps.supports_prompts());
+        assert!(caps.supports(Capability::ResourcesSubscribe));
+        assert!(!caps.supports(Capability::Sampling));
+    }
+
+    #[test]
+    fn test_list_changed_wire_name() {
+        let server: ServerCapabilities = serde_json::from_value(json!({
+            "tools": {"listChanged": true},
+            "resources": {"subscribe": true, "listChanged": true},
+            "prompts": {"listChanged": true}
+        }))
+        .unwrap();
+        assert!(server.supports_tools_list_changed());
+        assert!(server.supports_resources_list_changed());
+        assert!(server.supports_prompts_list_changed());
+        assert!(server
+            .check_allowed("notifications/tools/list_changed")
+            .is_ok());
+
+        let client: ClientCapabilities =
+            serde_json::from_value(json!({"roots": {"listChanged": true}})).unwrap();
+        assert!(client.supports_roots_list_changed());
+        assert_eq!(
+            serde_json::to_value(&client).unwrap(),
+            json!({"roots": {"listChanged": true}})
+        );
+    }
+
+    #[test]
+    fn test_check_allowed() {
+        let server = ServerCapabilities {
+            tools: Some(ToolsCapability { list_changed: None }),
+            ..Default::default()
+        };
+        assert!(server.check_allowed("tools/call").is_ok());
+        assert!(server.check_allowed("ping").is_ok());
+        assert!(server.check_allowed("sampling/createMessage").is_ok());
+
+        let error = server.check_allowed("resources/subscribe").unwrap_err();
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+        assert_eq!(
+            error.data,
+            Some(json!({"method": "resources/subscribe", "capability": "resources.subscribe"}))
+        );
+
+        let client = ClientCapabilities {
+            sampling: Some(SamplingCapability {}),
+            ..Default::default()
+        };
+        assert!(client.check_allowed("sampling/createMessage").is_ok());
+        assert!(client.check_allowed("roots/list").is_err());
+        assert!(client.check_allowed("tools/list").is_ok());
+
+        assert_eq!(Capability::Sampling.owner(), CapabilityOwner::Client);
+        assert_eq!(Capability::Tools.owner(), Side::Server);
+    }
+}
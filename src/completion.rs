@@ -0,0 +1,283 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Argument completion (`completion/complete`).
+//!
+//! Clients ask the server for suggested values of a prompt argument or a
+//! resource template variable while the user is typing. A response carries
+//! at most [`Completion::MAX_VALUES`] values.
+
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::protocol::McpRequest;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Request for completions of one argument
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompleteRequest {
+    /// Prompt or resource template the argument belongs to
+    #[serde(rename = "ref")]
+    pub reference: CompletionReference,
+    /// Argument being completed
+    pub argument: CompletionArgument,
+    /// Arguments already resolved, used to narrow the suggestions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<CompletionContext>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// What is being completed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CompletionReference {
+    /// Argument of a prompt
+    #[serde(rename = "ref/prompt")]
+    Prompt {
+        /// Prompt name
+        name: String,
+    },
+    /// Variable of a resource template
+    #[serde(rename = "ref/resource")]
+    Resource {
+        /// URI template
+        uri: String,
+    },
+}
+
+/// Argument name and the partial value typed so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletionArgument {
+    /// Argument name
+    pub name: String,
+    /// Current value
+    pub value: String,
+}
+
+/// Additional context for a completion request
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompletionContext {
+    /// Previously resolved arguments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<HashMap<String, String>>,
+}
+
+/// Response to a completion/complete request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompleteResult {
+    /// Suggested values
+    pub completion: Completion,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Suggested values for an argument
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Completion {
+    /// Suggested values, at most [`Completion::MAX_VALUES`]
+    #[serde(serialize_with = "serialize_values")]
+    #[serde(deserialize_with = "deserialize_values")]
+    pub values: Vec<String>,
+    /// Total number of available values, which may exceed `values`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// Whether more values exist than were returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "hasMore")]
+    pub has_more: Option<bool>,
+}
+
+/// Fail if `values` is longer than the specification allows
+fn check_values(values: &[String]) -> Result<(), McpError> {
+    if values.len() > Completion::MAX_VALUES {
+        return Err(McpError::invalid_params(format!(
+            "completion has {} values, at most {} are allowed",
+            values.len(),
+            Completion::MAX_VALUES
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse to send value lists longer than the specification allows
+fn serialize_values<S>(values: &Vec<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    check_values(values).map_err(|e| serde::ser::Error::custom(e.message))?;
+    values.serialize(serializer)
+}
+
+/// Reject value lists longer than the specification allows
+fn deserialize_values<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = Vec::<String>::deserialize(deserializer)?;
+    check_values(&values).map_err(|e| serde::de::Error::custom(e.message))?;
+    Ok(values)
+}
+
+impl McpRequest for CompleteRequest {
+    const METHOD: &'static str = "completion/complete";
+    type Result = CompleteResult;
+}
+
+impl CompleteRequest {
+    /// Ask for completions of `name`, given its current `value`
+    pub fn new(
+        reference: CompletionReference,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            reference,
+            argument: CompletionArgument {
+                name: name.into(),
+                value: value.into(),
+            },
+            context: None,
+            meta: None,
+        }
+    }
+
+    /// Add an already resolved argument to the context
+    pub fn with_context_argument(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.context
+            .get_or_insert_with(CompletionContext::default)
+            .arguments
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+impl CompletionReference {
+    /// Reference a prompt by name
+    pub fn prompt(name: impl Into<String>) -> Self {
+        Self::Prompt { name: name.into() }
+    }
+
+    /// Reference a resource template by its URI template
+    pub fn resource(uri: impl Into<String>) -> Self {
+        Self::Resource { uri: uri.into() }
+    }
+}
+
+impl CompleteResult {
+    /// Create a result from every matching value
+    ///
+    /// See [`Completion::new`] for how long lists are truncated.
+    pub fn new(values: Vec<String>) -> Self {
+        Self {
+            completion: Completion::new(values),
+            meta: None,
+        }
+    }
+}
+
+impl Completion {
+    /// Maximum number of values in one response
+    pub const MAX_VALUES: usize = 100;
+
+    /// Create a completion from every matching value
+    ///
+    /// Lists longer than [`Completion::MAX_VALUES`] are truncated, with
+    /// `total` and `has_more` reporting what was left out.
+    pub fn new(mut values: Vec<String>) -> Self {
+        if values.len() <= Self::MAX_VALUES {
+            return Self {
+                values,
+                total: None,
+                has_more: None,
+            };
+        }
+        let total = values.len() as u64;
+        values.truncate(Self::MAX_VALUES);
+        Self {
+            values,
+            total: Some(total),
+            has_more: Some(true),
+        }
+    }
+
+    /// Fail if there are more than [`Completion::MAX_VALUES`] values
+    pub fn validate(&self) -> Result<(), McpError> {
+        check_values(&self.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{JsonRpcResponse, RequestId, ResponseOutcome};
+    use serde_json::json;
+
+    #[test]
+    fn test_complete_request_wire_format() {
+        let request = CompleteRequest::new(
+            CompletionReference::resource("file:///{path}"),
+            "path",
+            "src/ma",
+        )
+        .with_context_argument("branch", "main");
+        let json_rpc = request.clone().into_request(RequestId::Number(1)).unwrap();
+        assert_eq!(json_rpc.method, "completion/complete");
+        assert_eq!(
+            json_rpc.params,
+            Some(json!({
+                "ref": {"type": "ref/resource", "uri": "file:///{path}"},
+                "argument": {"name": "path", "value": "src/ma"},
+                "context": {"arguments": {"branch": "main"}}
+            }))
+        );
+        assert_eq!(CompleteRequest::from_request(&json_rpc).unwrap(), request);
+    }
+
+    #[test]
+    fn test_completion_value_cap() {
+        let values: Vec<String> = (0..150).map(|i| format!("value-{}", i)).collect();
+        let result = CompleteResult::new(values);
+        assert_eq!(result.completion.values.len(), Completion::MAX_VALUES);
+        assert_eq!(result.completion.total, Some(150));
+        assert_eq!(result.completion.has_more, Some(true));
+
+        let short = CompleteResult::new(vec!["python".to_string()]);
+        assert_eq!(
+            serde_json::to_value(&short).unwrap(),
+            json!({"completion": {"values": ["python"]}})
+        );
+
+        let oversized = json!({"completion": {"values": vec!["x"; 101]}});
+        assert!(serde_json::from_value::<CompleteResult>(oversized).is_err());
+    }
+
+    #[test]
+    fn test_directly_built_completion_is_capped() {
+        let completion = Completion {
+            values: vec!["x".to_string(); Completion::MAX_VALUES + 1],
+            total: None,
+            has_more: None,
+        };
+        assert!(completion.validate().is_err());
+        assert!(serde_json::to_value(&completion).is_err());
+        let result = CompleteResult {
+            completion,
+            meta: None,
+        };
+        let response = JsonRpcResponse::from_result(RequestId::Number(1), Ok(result));
+        assert!(matches!(response.outcome, ResponseOutcome::Error(_)));
+
+        let capped = Completion::new(vec!["x".to_string(); 150]);
+        assert!(capped.validate().is_ok());
+    }
+}
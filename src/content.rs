@@ -0,0 +1,548 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Content blocks shared by tool results, prompt messages and sampling
+//! messages.
+//!
+//! [`ContentBlock`] is the single content type carried by
+//! `CallToolResult`, `PromptMessage` and `SamplingMessage`. Each of those
+//! contexts allows a subset of the variants (see [`ContentContext`]). The
+//! older types `ToolResultContent`, `PromptContent` and `MessageContent`
+//! remain, deprecated, with conversions to and from `ContentBlock`.
+
+use crate::annotations::{self, Annotations, Role};
+use crate::base64;
+use crate::errors::McpError;
+use crate::resources::{ResourceContents, ResourceLink};
+use crate::version::ProtocolVersion;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Content of a tool result, prompt message or sampling message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ContentBlock {
+    /// Text content
+    #[serde(rename = "text")]
+    Text {
+        /// Text content
+        text: String,
+        /// Hints about audience and priority
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// Image content
+    #[serde(rename = "image")]
+    Image {
+        /// Image data (base64 encoded)
+        data: String,
+        /// MIME type (e.g., "image/png")
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Hints about audience and priority
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// Audio content
+    #[serde(rename = "audio")]
+    Audio {
+        /// Audio data (base64 encoded)
+        data: String,
+        /// MIME type (e.g., "audio/wav")
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+        /// Hints about audience and priority
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// Embedded resource contents
+    #[serde(rename = "resource")]
+    Resource {
+        /// Text or blob contents of the resource
+        resource: ResourceContents,
+        /// Hints about audience and priority
+        #[serde(skip_serializing_if = "Option::is_none")]
+        annotations: Option<Annotations>,
+    },
+    /// Link to a resource the recipient may read separately
+    #[serde(rename = "resource_link")]
+    ResourceLink(ResourceLink),
+}
+
+/// Place a content block appears in, each allowing a subset of the variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentContext {
+    /// `CallToolResult.content`: every variant
+    ToolResult,
+    /// `PromptMessage.content`: every variant
+    Prompt,
+    /// `SamplingMessage.content`: text, image and audio; no resources
+    Sampling,
+}
+
+impl ContentBlock {
+    /// Create text content
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create image content from base64-encoded data
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: data.into(),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create audio content from base64-encoded data
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            mime_type: mime_type.into(),
+            annotations: None,
+        }
+    }
+
+    /// Create audio content from raw bytes
+    pub fn audio_from_bytes(bytes: &[u8], mime_type: impl Into<String>) -> Self {
+        Self::audio(base64::encode(bytes), mime_type)
+    }
+
+    /// Create embedded resource content
+    pub fn resource(contents: ResourceContents) -> Self {
+        Self::Resource {
+            resource: contents,
+            annotations: None,
+        }
+    }
+
+    /// Create a resource link
+    pub fn resource_link(link: ResourceLink) -> Self {
+        Self::ResourceLink(link)
+    }
+
+    /// Attach annotations
+    pub fn with_annotations(mut self, annotations: Annotations) -> Self {
+        match &mut self {
+            Self::Text {
+                annotations: slot, ..
+            }
+            | Self::Image {
+                annotations: slot, ..
+            }
+            | Self::Audio {
+                annotations: slot, ..
+            }
+            | Self::Resource {
+                annotations: slot, ..
+            } => *slot = Some(annotations),
+            Self::ResourceLink(link) => link.annotations = Some(annotations),
+        }
+        self
+    }
+
+    /// Annotations of this block, if any
+    pub fn annotations(&self) -> Option<&Annotations> {
+        match self {
+            Self::Text { annotations, .. }
+            | Self::Image { annotations, .. }
+            | Self::Audio { annotations, .. }
+            | Self::Resource { annotations, .. } => annotations.as_ref(),
+            Self::ResourceLink(link) => link.annotations.as_ref(),
+        }
+    }
+
+    /// Whether this block is meant for `role`, per its audience annotation
+    pub fn is_for(&self, role: Role) -> bool {
+        annotations::is_for(self.annotations(), role)
+    }
+
+    /// Name of the variant on the wire (the `type` field)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Text { .. } => "text",
+            Self::Image { .. } => "image",
+            Self::Audio { .. } => "audio",
+            Self::Resource { .. } => "resource",
+            Self::ResourceLink(_) => "resource_link",
+        }
+    }
+
+    /// Whether this block may appear in `context`
+    pub fn is_allowed_in(&self, context: ContentContext) -> bool {
+        match context {
+            ContentContext::ToolResult | ContentContext::Prompt => true,
+            ContentContext::Sampling => {
+                !matches!(self, Self::Resource { .. } | Self::ResourceLink(_))
+            }
+        }
+    }
+
+    /// Fail if this block may not appear in `context`
+    pub fn check_allowed_in(&self, context: ContentContext) -> Result<(), McpError> {
+        if self.is_allowed_in(context) {
+            Ok(())
+        } else {
+            Err(not_allowed(self, context))
+        }
+    }
+
+    /// Whether `version` allows this content
+    pub fn is_supported_by(&self, version: ProtocolVersion) -> bool {
+        match self {
+            Self::Audio { .. } => version.supports_audio(),
+            Self::ResourceLink(_) => version.supports_resource_links(),
+            _ => true,
+        }
+    }
+
+    /// Fail if `version` does not allow this content
+    pub fn check_supported_by(&self, version: ProtocolVersion) -> Result<(), McpError> {
+        if self.is_supported_by(version) {
+            Ok(())
+        } else {
+            Err(unsupported(self, version))
+        }
+    }
+
+    /// Replace content `version` does not allow with a text placeholder
+    pub fn downgrade_for(self, version: ProtocolVersion) -> Self {
+        match self {
+            Self::Audio {
+                mime_type,
+                annotations,
+                ..
+            } if !version.supports_audio() => Self::Text {
+                text: audio_placeholder(&mime_type, version),
+                annotations,
+            },
+            Self::ResourceLink(link) if !version.supports_resource_links() => Self::Text {
+                text: resource_link_placeholder(&link.uri, version),
+                annotations: link.annotations,
+            },
+            content => content,
+        }
+    }
+}
+
+impl fmt::Display for ContentContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ToolResult => "tool results",
+            Self::Prompt => "prompt messages",
+            Self::Sampling => "sampling messages",
+        })
+    }
+}
+
+/// Error for a block placed in a context that does not allow it
+fn not_allowed(content: &ContentBlock, context: ContentContext) -> McpError {
+    McpError::invalid_params(format!(
+        "{} content is not allowed in {}",
+        content.kind(),
+        context
+    ))
+}
+
+/// Text standing in for audio content when `version` does not allow it
+fn audio_placeholder(mime_type: &str, version: ProtocolVersion) -> String {
+    format!(
+        "[{} audio omitted: not supported by protocol version {}]",
+        mime_type, version
+    )
+}
+
+/// Text standing in for a resource link when `version` does not allow it
+fn resource_link_placeholder(uri: &str, version: ProtocolVersion) -> String {
+    format!(
+        "[resource link {} omitted: not supported by protocol version {}]",
+        uri, version
+    )
+}
+
+/// Error for a block sent under a `version` that does not allow it
+fn unsupported(content: &ContentBlock, version: ProtocolVersion) -> McpError {
+    let required = match content {
+        ContentBlock::ResourceLink(_) => ProtocolVersion::V2025_06_18,
+        _ => ProtocolVersion::V2025_03_26,
+    };
+    McpError::invalid_params(format!(
+        "{} content requires protocol version {} or later, negotiated {}",
+        content.kind(),
+        required,
+        version
+    ))
+}
+
+/// Error for a block a legacy content type named `target` cannot hold
+pub(crate) fn no_legacy_equivalent(content: &ContentBlock, target: &str) -> McpError {
+    McpError::invalid_params(format!(
+        "{} content cannot be converted to {}",
+        content.kind(),
+        target
+    ))
+}
+
+/// Block standing in for a legacy `resource` variant, which held only a URI
+pub(crate) fn legacy_resource(uri: String) -> ContentBlock {
+    ContentBlock::ResourceLink(ResourceLink::new(uri.clone(), uri))
+}
+
+/// Serialize a block, rejecting variants `SamplingMessage` does not allow
+pub(crate) fn serialize_sampling_content<S>(
+    content: &ContentBlock,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    content
+        .check_allowed_in(ContentContext::Sampling)
+        .map_err(|e| serde::ser::Error::custom(e.message))?;
+    content.serialize(serializer)
+}
+
+/// Deserialize a block, rejecting variants `SamplingMessage` does not allow
+pub(crate) fn deserialize_sampling_content<'de, D>(
+    deserializer: D,
+) -> Result<ContentBlock, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let content = ContentBlock::deserialize(deserializer)?;
+    content
+        .check_allowed_in(ContentContext::Sampling)
+        .map_err(|e| serde::de::Error::custom(e.message))?;
+    Ok(content)
+}
+
+impl From<ResourceContents> for ContentBlock {
+    fn from(contents: ResourceContents) -> Self {
+        Self::resource(contents)
+    }
+}
+
+impl From<ResourceLink> for ContentBlock {
+    fn from(link: ResourceLink) -> Self {
+        Self::ResourceLink(link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::ReadResourceResult;
+    use crate::sampling::{CreateMessageRequest, MessageRole, SamplingMessage};
+    use serde_json::json;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_legacy_conversions() {
+        use crate::prompts::PromptContent;
+        use crate::sampling::MessageContent;
+        use crate::tools::ToolResultContent;
+
+        let text = ToolResultContent::Text {
+            text: "hi".to_string(),
+        };
+        assert_eq!(ContentBlock::from(text.clone()), ContentBlock::text("hi"));
+        assert_eq!(
+            ToolResultContent::try_from(ContentBlock::text("hi")).unwrap(),
+            text
+        );
+        assert_eq!(
+            ContentBlock::from(MessageContent::image("aGk=", "image/png")),
+            ContentBlock::image("aGk=", "image/png")
+        );
+        let link = ContentBlock::from(PromptContent::Resource {
+            resource: "file:///a.txt".to_string(),
+        });
+        assert_eq!(link.kind(), "resource_link");
+        assert!(matches!(
+            PromptContent::try_from(link.clone()).unwrap(),
+            PromptContent::Resource { resource } if resource == "file:///a.txt"
+        ));
+        let error = MessageContent::try_from(link).unwrap_err();
+        assert_eq!(
+            error.message,
+            "resource_link content cannot be converted to MessageContent"
+        );
+        let embedded = ContentBlock::resource(ResourceContents::text("file:///a.txt", "hello"));
+        assert!(ToolResultContent::try_from(embedded).is_err());
+
+        let audio = ContentBlock::audio_from_bytes(b"RIFF", "audio/wav");
+        assert_eq!(
+            serde_json::to_value(&audio).unwrap(),
+            json!({"type": "audio", "data": "UklGRg==", "mimeType": "audio/wav"})
+        );
+    }
+
+    #[test]
+    fn test_context_subsets() {
+        let resource = ContentBlock::resource(ResourceContents::text("file:///a.txt", "hello"));
+        assert!(resource
+            .check_allowed_in(ContentContext::ToolResult)
+            .is_ok());
+        assert!(resource.check_allowed_in(ContentContext::Prompt).is_ok());
+        let error = resource
+            .check_allowed_in(ContentContext::Sampling)
+            .unwrap_err();
+        assert_eq!(
+            error.message,
+            "resource content is not allowed in sampling messages"
+        );
+        let link = ContentBlock::resource_link(ResourceLink::new("file:///a.txt", "a.txt"));
+        assert!(link.is_allowed_in(ContentContext::Prompt));
+        let error = link.check_allowed_in(ContentContext::Sampling).unwrap_err();
+        assert_eq!(
+            error.message,
+            "resource_link content is not allowed in sampling messages"
+        );
+
+        assert!(ContentBlock::text("hi").is_allowed_in(ContentContext::Sampling));
+    }
+
+    #[test]
+    fn test_sampling_message_rejects_resources() {
+        let message = json!({
+            "role": "user",
+            "content": {
+                "type": "resource",
+                "resource": {"uri": "file:///a.txt", "text": "hello"}
+            }
+        });
+        assert!(serde_json::from_value::<SamplingMessage>(message).is_err());
+        let message = json!({
+            "role": "user",
+            "content": {"type": "resource_link", "uri": "file:///a.txt", "name": "a.txt"}
+        });
+        assert!(serde_json::from_value::<SamplingMessage>(message).is_err());
+
+        let message = json!({"role": "user", "content": {"type": "text", "text": "hi"}});
+        let parsed: SamplingMessage = serde_json::from_value(message).unwrap();
+        assert_eq!(parsed.role, MessageRole::User);
+
+        let resource = ContentBlock::resource(ResourceContents::text("file:///a.txt", "hello"));
+        assert!(SamplingMessage::new(MessageRole::User, resource.clone()).is_err());
+        let smuggled = SamplingMessage {
+            role: MessageRole::User,
+            content: resource,
+        };
+        assert!(serde_json::to_value(&smuggled).is_err());
+        let message = SamplingMessage::new(MessageRole::User, ContentBlock::text("hi")).unwrap();
+        assert_eq!(message, parsed);
+    }
+
+    #[test]
+    fn test_embedded_resource_wire_format() {
+        let blob = ContentBlock::resource(ResourceContents::blob(
+            "file:///logo.png",
+            "aGk=",
+            "image/png",
+        ));
+        let json = serde_json::to_value(&blob).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "resource",
+                "resource": {"uri": "file:///logo.png", "blob": "aGk=", "mimeType": "image/png"}
+            })
+        );
+        assert_eq!(serde_json::from_value::<ContentBlock>(json).unwrap(), blob);
+
+        let link = ResourceLink::new("file:///main.rs", "main.rs")
+            .with_mime_type("text/x-rust")
+            .with_size(42);
+        assert_eq!(
+            serde_json::to_value(ContentBlock::from(link)).unwrap(),
+            json!({
+                "type": "resource_link",
+                "uri": "file:///main.rs",
+                "name": "main.rs",
+                "mimeType": "text/x-rust",
+                "size": 42
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_resource_result_into_content() {
+        let result = ReadResourceResult {
+            contents: vec![
+                ResourceContents::text("file:///a.txt", "a"),
+                ResourceContents::blob("file:///b.bin", "Yg==", "application/octet-stream"),
+            ],
+            meta: None,
+        };
+        let content = result.into_content();
+        assert_eq!(content.len(), 2);
+        assert!(content.iter().all(|block| block.kind() == "resource"));
+        assert!(matches!(
+            &content[1],
+            ContentBlock::Resource { resource, .. } if resource.uri() == "file:///b.bin"
+        ));
+    }
+
+    #[test]
+    fn test_resource_link_downgrade() {
+        let link = ContentBlock::resource_link(ResourceLink::new("file:///a.txt", "a.txt"));
+        assert!(link.is_supported_by(ProtocolVersion::V2025_06_18));
+        assert!(!link.is_supported_by(ProtocolVersion::V2025_03_26));
+        assert_eq!(
+            link.downgrade_for(ProtocolVersion::V2025_03_26),
+            ContentBlock::text(
+                "[resource link file:///a.txt omitted: not supported by protocol version 2025-03-26]"
+            )
+        );
+    }
+
+    #[test]
+    fn test_sampling_version_check() {
+        let request: CreateMessageRequest = serde_json::from_value(json!({
+            "messages": [
+                {"role": "user", "content": {"type": "text", "text": "hi"}},
+                {"role": "user", "content": {"type": "audio", "data": "UklGRg==", "mimeType": "audio/wav"}}
+            ]
+        }))
+        .unwrap();
+        assert!(request.check_version(ProtocolVersion::V2025_03_26).is_ok());
+        let error = request
+            .check_version(ProtocolVersion::V2024_11_05)
+            .unwrap_err();
+        assert_eq!(
+            error.message,
+            "audio content requires protocol version 2025-03-26 or later, negotiated 2024-11-05"
+        );
+
+        let link = ContentBlock::resource_link(ResourceLink::new("file:///a.txt", "a.txt"));
+        let error = link
+            .check_supported_by(ProtocolVersion::V2025_03_26)
+            .unwrap_err();
+        assert_eq!(
+            error.message,
+            "resource_link content requires protocol version 2025-06-18 or later, negotiated 2025-03-26"
+        );
+    }
+
+    #[test]
+    fn test_annotations_and_audience() {
+        let for_user = Annotations::new().for_audience([Role::User]);
+        let text = ContentBlock::text("for the user").with_annotations(for_user.clone());
+        assert_eq!(
+            serde_json::to_value(&text).unwrap(),
+            json!({"type": "text", "text": "for the user", "annotations": {"audience": ["user"]}})
+        );
+        assert!(text.is_for(Role::User));
+        assert!(!text.is_for(Role::Assistant));
+        assert!(ContentBlock::text("anyone").is_for(Role::Assistant));
+
+        let audio = ContentBlock::audio("UklGRg==", "audio/wav").with_annotations(for_user.clone());
+        let downgraded = audio.downgrade_for(ProtocolVersion::V2024_11_05);
+        assert_eq!(downgraded.kind(), "text");
+        assert_eq!(downgraded.annotations(), Some(&for_user));
+    }
+}
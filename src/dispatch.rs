@@ -0,0 +1,335 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Typed dispatch of incoming requests and notifications.
+//!
+//! Each enum covers every message one side of the connection may send, so a
+//! router can turn a raw `JsonRpcRequest` or `JsonRpcNotification` into a
+//! typed value with a single `try_from` and handle it with one `match`.
+
+use crate::cancellation::CancelledNotification;
+use crate::completion::CompleteRequest;
+use crate::elicitation::ElicitRequest;
+use crate::errors::McpError;
+use crate::logging::{LogEntry, SetLoggingLevelRequest};
+use crate::progress::ProgressNotification;
+use crate::prompts::{GetPromptRequest, ListPromptsRequest, PromptListChangedNotification};
+use crate::protocol::{
+    InitializeParams, InitializedNotification, JsonRpcNotification, JsonRpcRequest,
+    McpNotification, McpRequest, PingRequest, RequestId,
+};
+use crate::resources::{
+    ListResourceTemplatesRequest, ListResourcesRequest, ReadResourceRequest,
+    ResourceListChangedNotification, ResourceUpdatedNotification, SubscribeRequest,
+    UnsubscribeRequest,
+};
+use crate::roots::{ListRootsRequest, RootsListChangedNotification};
+use crate::sampling::CreateMessageRequest;
+use crate::tools::{CallToolRequest, ListToolsRequest, ToolListChangedNotification};
+
+/// Define a request enum whose variants each wrap an [`McpRequest`] type
+macro_rules! request_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $( $(#[$variant_meta:meta])* $variant:ident($params:ty), )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum $name {
+            $( $(#[$variant_meta])* $variant($params), )*
+        }
+
+        impl $name {
+            /// Method name of the request
+            pub fn method(&self) -> &'static str {
+                match self {
+                    $( Self::$variant(_) => <$params as McpRequest>::METHOD, )*
+                }
+            }
+
+            /// Build a JSON-RPC request carrying this request's parameters
+            pub fn into_request(self, id: RequestId) -> Result<JsonRpcRequest, McpError> {
+                match self {
+                    $( Self::$variant(params) => params.into_request(id), )*
+                }
+            }
+        }
+
+        impl TryFrom<&JsonRpcRequest> for $name {
+            type Error = McpError;
+
+            /// Dispatch on the method name, decoding the matching params type
+            fn try_from(request: &JsonRpcRequest) -> Result<Self, McpError> {
+                match request.method.as_str() {
+                    $(
+                        <$params as McpRequest>::METHOD => {
+                            <$params as McpRequest>::from_request(request).map(Self::$variant)
+                        }
+                    )*
+                    other => Err(McpError::method_not_found(other)),
+                }
+            }
+        }
+
+        impl TryFrom<JsonRpcRequest> for $name {
+            type Error = McpError;
+
+            fn try_from(request: JsonRpcRequest) -> Result<Self, McpError> {
+                Self::try_from(&request)
+            }
+        }
+
+        $(
+            impl From<$params> for $name {
+                fn from(params: $params) -> Self {
+                    Self::$variant(params)
+                }
+            }
+        )*
+    };
+}
+
+/// Define a notification enum whose variants each wrap an [`McpNotification`] type
+macro_rules! notification_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $( $(#[$variant_meta:meta])* $variant:ident($params:ty), )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum $name {
+            $( $(#[$variant_meta])* $variant($params), )*
+        }
+
+        impl $name {
+            /// Method name of the notification
+            pub fn method(&self) -> &'static str {
+                match self {
+                    $( Self::$variant(_) => <$params as McpNotification>::METHOD, )*
+                }
+            }
+
+            /// Build the JSON-RPC notification
+            pub fn into_notification(self) -> Result<JsonRpcNotification, McpError> {
+                match self {
+                    $( Self::$variant(params) => params.into_notification(), )*
+                }
+            }
+        }
+
+        impl TryFrom<&JsonRpcNotification> for $name {
+            type Error = McpError;
+
+            /// Dispatch on the method name, decoding the matching params type
+            fn try_from(notification: &JsonRpcNotification) -> Result<Self, McpError> {
+                match notification.method.as_str() {
+                    $(
+                        <$params as McpNotification>::METHOD => {
+                            <$params as McpNotification>::from_notification(notification)
+                                .map(Self::$variant)
+                        }
+                    )*
+                    other => Err(McpError::method_not_found(other)),
+                }
+            }
+        }
+
+        impl TryFrom<JsonRpcNotification> for $name {
+            type Error = McpError;
+
+            fn try_from(notification: JsonRpcNotification) -> Result<Self, McpError> {
+                Self::try_from(&notification)
+            }
+        }
+
+        $(
+            impl From<$params> for $name {
+                fn from(params: $params) -> Self {
+                    Self::$variant(params)
+                }
+            }
+        )*
+    };
+}
+
+request_enum! {
+    /// Requests sent from client to server
+    ClientRequest {
+        /// `initialize`
+        Initialize(InitializeParams),
+        /// `ping`
+        Ping(PingRequest),
+        /// `tools/list`
+        ListTools(ListToolsRequest),
+        /// `tools/call`
+        CallTool(CallToolRequest),
+        /// `resources/list`
+        ListResources(ListResourcesRequest),
+        /// `resources/templates/list`
+        ListResourceTemplates(ListResourceTemplatesRequest),
+        /// `resources/read`
+        ReadResource(ReadResourceRequest),
+        /// `resources/subscribe`
+        Subscribe(SubscribeRequest),
+        /// `resources/unsubscribe`
+        Unsubscribe(UnsubscribeRequest),
+        /// `prompts/list`
+        ListPrompts(ListPromptsRequest),
+        /// `prompts/get`
+        GetPrompt(GetPromptRequest),
+        /// `logging/setLevel`
+        SetLoggingLevel(SetLoggingLevelRequest),
+        /// `completion/complete`
+        Complete(CompleteRequest),
+    }
+}
+
+request_enum! {
+    /// Requests sent from server to client
+    ServerRequest {
+        /// `ping`
+        Ping(PingRequest),
+        /// `roots/list`
+        ListRoots(ListRootsRequest),
+        /// `sampling/createMessage`
+        CreateMessage(CreateMessageRequest),
+        /// `elicitation/create`
+        Elicit(ElicitRequest),
+    }
+}
+
+notification_enum! {
+    /// Notifications sent from client to server
+    ClientNotification {
+        /// `notifications/cancelled`
+        Cancelled(CancelledNotification),
+        /// `notifications/progress`
+        Progress(ProgressNotification),
+        /// `notifications/initialized`
+        Initialized(InitializedNotification),
+        /// `notifications/roots/list_changed`
+        RootsListChanged(RootsListChangedNotification),
+    }
+}
+
+notification_enum! {
+    /// Notifications sent from server to client
+    ServerNotification {
+        /// `notifications/cancelled`
+        Cancelled(CancelledNotification),
+        /// `notifications/progress`
+        Progress(ProgressNotification),
+        /// `notifications/message`
+        LoggingMessage(LogEntry),
+        /// `notifications/tools/list_changed`
+        ToolListChanged(ToolListChangedNotification),
+        /// `notifications/resources/list_changed`
+        ResourceListChanged(ResourceListChangedNotification),
+        /// `notifications/resources/updated`
+        ResourceUpdated(ResourceUpdatedNotification),
+        /// `notifications/prompts/list_changed`
+        PromptListChanged(PromptListChangedNotification),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorCode;
+    use crate::logging::LoggingLevel;
+    use serde_json::json;
+
+    #[test]
+    fn test_client_request_dispatch() {
+        let request = JsonRpcRequest::with_params(
+            RequestId::Number(1),
+            "tools/call",
+            json!({"name": "calculate", "arguments": {"expression": "2 + 2"}}),
+        );
+        match ClientRequest::try_from(request).unwrap() {
+            ClientRequest::CallTool(call) => assert_eq!(call.name, "calculate"),
+            other => panic!("unexpected request: {:?}", other),
+        }
+
+        let request = JsonRpcRequest::new(RequestId::Number(2), "tools/list");
+        assert!(matches!(
+            ClientRequest::try_from(request).unwrap(),
+            ClientRequest::ListTools(_)
+        ));
+    }
+
+    #[test]
+    fn test_dispatch_errors() {
+        let unknown = JsonRpcRequest::new(RequestId::Number(1), "tools/unknown");
+        let error = ClientRequest::try_from(unknown).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+
+        let wrong_side = JsonRpcRequest::new(RequestId::Number(2), "sampling/createMessage");
+        let error = ClientRequest::try_from(wrong_side).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+
+        let bad_params =
+            JsonRpcRequest::with_params(RequestId::Number(3), "resources/read", json!({"uri": 5}));
+        let error = ClientRequest::try_from(bad_params).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams);
+    }
+
+    #[test]
+    fn test_request_enum_round_trip() {
+        let request = ServerRequest::from(PingRequest::default());
+        assert_eq!(request.method(), "ping");
+        let json_rpc = request.clone().into_request(RequestId::Number(7)).unwrap();
+        assert_eq!(ServerRequest::try_from(&json_rpc).unwrap(), request);
+    }
+
+    #[test]
+    fn test_notification_dispatch() {
+        let notification = ServerNotification::LoggingMessage(LogEntry::new(
+            LoggingLevel::Info,
+            json!("server started"),
+        ));
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.method, "notifications/message");
+        assert_eq!(
+            ServerNotification::try_from(json_rpc).unwrap(),
+            notification
+        );
+
+        let initialized = ClientNotification::from(InitializedNotification::default());
+        let json_rpc = initialized.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.params, None);
+        assert_eq!(ClientNotification::try_from(json_rpc).unwrap(), initialized);
+
+        let updated = JsonRpcNotification::with_params(
+            "notifications/resources/updated",
+            json!({"uri": "file:///project/src/main.rs"}),
+        );
+        match ServerNotification::try_from(&updated).unwrap() {
+            ServerNotification::ResourceUpdated(updated) => {
+                assert_eq!(updated.uri, "file:///project/src/main.rs")
+            }
+            other => panic!("unexpected notification: {:?}", other),
+        }
+
+        let cancelled = JsonRpcNotification::with_params(
+            "notifications/cancelled",
+            json!({"requestId": "abc", "reason": "timeout"}),
+        );
+        assert!(matches!(
+            ClientNotification::try_from(cancelled).unwrap(),
+            ClientNotification::Cancelled(CancelledNotification {
+                request_id: RequestId::String(_),
+                ..
+            })
+        ));
+
+        let unknown = JsonRpcNotification::new("notifications/unknown");
+        let error = ClientNotification::try_from(unknown).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+    }
+}
@@ -0,0 +1,424 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Requests for user input (`elicitation/create`).
+//!
+//! A server may ask the client to collect information from the user while
+//! handling a request. The requested schema is restricted to a flat object
+//! whose properties are strings, numbers, integers, booleans or string
+//! enums; nested objects and arrays are rejected when parsing.
+
+#[cfg(feature = "validation")]
+use crate::errors::{ErrorCode, McpError};
+use crate::meta::Meta;
+use crate::protocol::McpRequest;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Request asking the user for structured input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitRequest {
+    /// Message explaining what is being asked
+    pub message: String,
+    /// Shape of the expected response
+    #[serde(rename = "requestedSchema")]
+    pub requested_schema: ElicitationSchema,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Flat object schema describing the requested input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitationSchema {
+    /// Schema type, always "object"
+    #[serde(rename = "type", deserialize_with = "deserialize_object_type")]
+    pub type_: String,
+    /// Requested fields
+    pub properties: HashMap<String, PrimitiveSchema>,
+    /// Names of the fields the user must fill in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Vec<String>>,
+}
+
+/// Schema of a single requested field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PrimitiveSchema {
+    /// Free text, or one of a fixed set of strings
+    #[serde(rename = "string")]
+    String(StringSchema),
+    /// Any number
+    #[serde(rename = "number")]
+    Number(NumberSchema),
+    /// Whole number
+    #[serde(rename = "integer")]
+    Integer(NumberSchema),
+    /// Yes/no choice
+    #[serde(rename = "boolean")]
+    Boolean(BooleanSchema),
+}
+
+/// String field, optionally restricted to an enumeration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StringSchema {
+    /// Display title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Field description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Minimum length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "minLength")]
+    pub min_length: Option<u64>,
+    /// Maximum length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "maxLength")]
+    pub max_length: Option<u64>,
+    /// Expected format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<StringFormat>,
+    /// Allowed values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<String>>,
+    /// Display names of the allowed values, in the same order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "enumNames")]
+    pub enum_names: Option<Vec<String>>,
+}
+
+/// Formats a string field may require
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StringFormat {
+    /// Email address
+    #[serde(rename = "email")]
+    Email,
+    /// URI
+    #[serde(rename = "uri")]
+    Uri,
+    /// Calendar date
+    #[serde(rename = "date")]
+    Date,
+    /// Date and time
+    #[serde(rename = "date-time")]
+    DateTime,
+}
+
+/// Number or integer field
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NumberSchema {
+    /// Display title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Field description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Minimum value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+    /// Maximum value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+}
+
+/// Boolean field
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BooleanSchema {
+    /// Display title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Field description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Default value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+/// User's response to an elicitation request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitResult {
+    /// What the user did
+    pub action: ElicitAction,
+    /// Submitted values, present when the user accepted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<HashMap<String, ElicitValue>>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// User action in response to an elicitation request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElicitAction {
+    /// The user submitted the requested data
+    Accept,
+    /// The user explicitly refused
+    Decline,
+    /// The user dismissed the request without choosing
+    Cancel,
+}
+
+/// Value submitted for a single field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ElicitValue {
+    /// String or enum field
+    String(String),
+    /// Number or integer field
+    Number(serde_json::Number),
+    /// Boolean field
+    Boolean(bool),
+}
+
+/// Accept only `"object"` as the type of an elicitation schema
+fn deserialize_object_type<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let type_ = String::deserialize(deserializer)?;
+    if type_ != "object" {
+        return Err(serde::de::Error::custom(format!(
+            "elicitation schema must have type \"object\", got \"{}\"",
+            type_
+        )));
+    }
+    Ok(type_)
+}
+
+impl McpRequest for ElicitRequest {
+    const METHOD: &'static str = "elicitation/create";
+    type Result = ElicitResult;
+}
+
+impl ElicitRequest {
+    /// Ask the user for input matching `requested_schema`
+    pub fn new(message: impl Into<String>, requested_schema: ElicitationSchema) -> Self {
+        Self {
+            message: message.into(),
+            requested_schema,
+            meta: None,
+        }
+    }
+
+    /// Check a response against the requested schema
+    ///
+    /// Declined and cancelled responses always pass; accepted responses
+    /// must carry content matching the schema.
+    #[cfg(feature = "validation")]
+    pub fn validate_result(&self, result: &ElicitResult) -> Result<(), McpError> {
+        if result.action != ElicitAction::Accept {
+            return Ok(());
+        }
+        let schema = serde_json::to_value(&self.requested_schema)
+            .map_err(|e| McpError::internal_error(format!("Invalid schema: {}", e)))?;
+        let content = serde_json::to_value(result.content.clone().unwrap_or_default())
+            .map_err(|e| McpError::internal_error(format!("Invalid content: {}", e)))?;
+        let violations = crate::validation::schema_violations(&schema, &content)?;
+        if violations.is_empty() {
+            return Ok(());
+        }
+        Err(McpError::with_data(
+            ErrorCode::InvalidParams,
+            "Elicitation response does not match the requested schema",
+            serde_json::json!({ "errors": violations }),
+        ))
+    }
+}
+
+impl ElicitationSchema {
+    /// Create a schema with no fields
+    pub fn new() -> Self {
+        Self {
+            type_: "object".to_string(),
+            properties: HashMap::new(),
+            required: None,
+        }
+    }
+
+    /// Add a field, marking it required if asked
+    pub fn with_property(
+        mut self,
+        name: impl Into<String>,
+        schema: PrimitiveSchema,
+        required: bool,
+    ) -> Self {
+        let name = name.into();
+        if required {
+            self.required
+                .get_or_insert_with(Vec::new)
+                .push(name.clone());
+        }
+        self.properties.insert(name, schema);
+        self
+    }
+}
+
+impl Default for ElicitationSchema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElicitResult {
+    /// The user submitted `content`
+    pub fn accept(content: HashMap<String, ElicitValue>) -> Self {
+        Self {
+            action: ElicitAction::Accept,
+            content: Some(content),
+            meta: None,
+        }
+    }
+
+    /// The user explicitly refused
+    pub fn decline() -> Self {
+        Self {
+            action: ElicitAction::Decline,
+            content: None,
+            meta: None,
+        }
+    }
+
+    /// The user dismissed the request
+    pub fn cancel() -> Self {
+        Self {
+            action: ElicitAction::Cancel,
+            content: None,
+            meta: None,
+        }
+    }
+}
+
+impl From<String> for ElicitValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for ElicitValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<i64> for ElicitValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<bool> for ElicitValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn contact_request() -> ElicitRequest {
+        let schema = ElicitationSchema::new()
+            .with_property(
+                "email",
+                PrimitiveSchema::String(StringSchema {
+                    format: Some(StringFormat::Email),
+                    ..Default::default()
+                }),
+                true,
+            )
+            .with_property(
+                "age",
+                PrimitiveSchema::Integer(NumberSchema {
+                    minimum: Some(0.0),
+                    ..Default::default()
+                }),
+                false,
+            );
+        ElicitRequest::new("How can we reach you?", schema)
+    }
+
+    #[test]
+    fn test_elicit_request_wire_format() {
+        let params = serde_json::to_value(contact_request()).unwrap();
+        assert_eq!(
+            params,
+            json!({
+                "message": "How can we reach you?",
+                "requestedSchema": {
+                    "type": "object",
+                    "properties": {
+                        "email": {"type": "string", "format": "email"},
+                        "age": {"type": "integer", "minimum": 0.0}
+                    },
+                    "required": ["email"]
+                }
+            })
+        );
+        let parsed: ElicitRequest = serde_json::from_value(params).unwrap();
+        assert_eq!(parsed, contact_request());
+    }
+
+    #[test]
+    fn test_schema_rejects_non_primitive_fields() {
+        let nested = json!({
+            "message": "?",
+            "requestedSchema": {
+                "type": "object",
+                "properties": {"address": {"type": "object", "properties": {}}}
+            }
+        });
+        assert!(serde_json::from_value::<ElicitRequest>(nested).is_err());
+
+        let array = json!({"message": "?", "requestedSchema": {"type": "array", "properties": {}}});
+        assert!(serde_json::from_value::<ElicitRequest>(array).is_err());
+    }
+
+    #[test]
+    fn test_elicit_result() {
+        let result = ElicitResult::accept(HashMap::from([
+            ("email".to_string(), ElicitValue::from("a@example.com")),
+            ("age".to_string(), ElicitValue::from(30)),
+        ]));
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["action"], "accept");
+        assert_eq!(json["content"]["age"], 30);
+        assert_eq!(
+            serde_json::from_value::<ElicitResult>(json).unwrap(),
+            result
+        );
+
+        assert_eq!(
+            serde_json::to_value(ElicitResult::decline()).unwrap(),
+            json!({"action": "decline"})
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_validate_result() {
+        let request = contact_request();
+        let valid = ElicitResult::accept(HashMap::from([(
+            "email".to_string(),
+            ElicitValue::from("a@example.com"),
+        )]));
+        assert!(request.validate_result(&valid).is_ok());
+
+        let missing = ElicitResult::accept(HashMap::from([("age".to_string(), 30.into())]));
+        let error = request.validate_result(&missing).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams);
+
+        let negative = ElicitResult::accept(HashMap::from([
+            ("email".to_string(), "a@example.com".into()),
+            ("age".to_string(), (-1).into()),
+        ]));
+        assert!(request.validate_result(&negative).is_err());
+        assert!(request.validate_result(&ElicitResult::cancel()).is_ok());
+    }
+}
@@ -1,27 +1,73 @@
 //! Error types and codes for MCP protocol operations.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use thiserror::Error;
 
 /// MCP error codes as defined in the specification
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serialized as the JSON-RPC integer code. Codes without a named variant,
+/// such as MCP's -32002 (resource not found) or server-defined codes, are
+/// kept as [`ErrorCode::Other`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorCode {
     /// Invalid JSON was received by the server
-    #[serde(rename = "-32700")]
-    ParseError = -32700,
+    ParseError,
     /// The JSON sent is not a valid Request object
-    #[serde(rename = "-32600")]
-    InvalidRequest = -32600,
+    InvalidRequest,
     /// The method does not exist / is not available
-    #[serde(rename = "-32601")]
-    MethodNotFound = -32601,
+    MethodNotFound,
     /// Invalid method parameter(s)
-    #[serde(rename = "-32602")]
-    InvalidParams = -32602,
+    InvalidParams,
     /// Internal JSON-RPC error
-    #[serde(rename = "-32603")]
-    InternalError = -32603,
+    InternalError,
+    /// Any other code; build it with [`ErrorCode::from_code`] so that
+    /// standard codes map to their named variants
+    Other(i64),
+}
+
+impl ErrorCode {
+    /// Integer code carried on the wire
+    pub fn code(&self) -> i64 {
+        match self {
+            Self::ParseError => -32700,
+            Self::InvalidRequest => -32600,
+            Self::MethodNotFound => -32601,
+            Self::InvalidParams => -32602,
+            Self::InternalError => -32603,
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Error code for an integer `code`
+    pub fn from_code(code: i64) -> Self {
+        match code {
+            -32700 => Self::ParseError,
+            -32600 => Self::InvalidRequest,
+            -32601 => Self::MethodNotFound,
+            -32602 => Self::InvalidParams,
+            -32603 => Self::InternalError,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Self::from_code)
+    }
 }
 
 /// MCP error structure for JSON-RPC responses
@@ -98,6 +144,25 @@ mod tests {
         assert_eq!(error, deserialized);
     }
 
+    #[test]
+    fn test_error_code_is_integer() {
+        let error = McpError::parse_error("Parse error");
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({"code": -32700, "message": "Parse error"})
+        );
+        let parsed: McpError =
+            serde_json::from_value(json!({"code": -32601, "message": "Method not found: x"}))
+                .unwrap();
+        assert_eq!(parsed.code, ErrorCode::MethodNotFound);
+        assert!(serde_json::from_value::<ErrorCode>(json!("-32700")).is_err());
+
+        let other: ErrorCode = serde_json::from_value(json!(-1)).unwrap();
+        assert_eq!(other, ErrorCode::Other(-1));
+        assert_eq!(serde_json::to_value(other).unwrap(), json!(-1));
+        assert_eq!(ErrorCode::from_code(-32602), ErrorCode::InvalidParams);
+    }
+
     #[test]
     fn test_error_with_data() {
         let error = McpError::with_data(
@@ -8,13 +8,57 @@
 //! This crate provides the core type definitions, request/response structures,
 //! and error types used throughout the MCP Rust ecosystem.
 
-pub mod error;
-pub mod jsonrpc;
-pub mod mcp;
+pub mod annotations;
+mod base64;
+pub mod cancellation;
+pub mod capabilities;
+pub mod completion;
+pub mod content;
+pub mod dispatch;
+pub mod elicitation;
+pub mod errors;
+pub mod lifecycle;
+pub mod logging;
+pub mod meta;
+pub mod metadata;
+pub mod pending;
+pub mod progress;
+pub mod prompts;
+pub mod protocol;
+pub mod resources;
+pub mod roots;
+pub mod sampling;
+pub mod subscriptions;
+pub mod tools;
+#[cfg(feature = "validation")]
+mod validation;
+pub mod version;
 
-pub use error::{ErrorCode, McpError};
-pub use jsonrpc::{JsonRpc, JsonRpcRequest, JsonRpcResponse, RequestId};
-pub use mcp::*;
+pub use annotations::{Annotations, Role, Timestamp};
+pub use cancellation::{CancellationRegistry, CancellationToken, CancelledNotification};
+pub use capabilities::{Capability, CapabilityOwner};
+pub use completion::*;
+pub use content::{ContentBlock, ContentContext};
+pub use dispatch::*;
+pub use elicitation::*;
+pub use errors::{ErrorCode, McpError};
+pub use lifecycle::{Lifecycle, LifecycleState};
+pub use logging::*;
+pub use meta::Meta;
+pub use metadata::{BaseMetadata, Icon};
+pub use pending::*;
+pub use progress::{ProgressNotification, ProgressToken, ProgressTracker};
+pub use prompts::*;
+pub use protocol::*;
+pub use resources::*;
+pub use roots::*;
+pub use sampling::*;
+pub use subscriptions::SubscriptionSet;
+pub use tools::*;
+pub use version::{accept_server_version, negotiate, ProtocolVersion};
 
 /// Re-export commonly used types from serde_json
 pub use serde_json::{json, Value};
+
+/// JSON-RPC version string carried by every message
+pub const JSONRPC_VERSION: &str = "2.0";
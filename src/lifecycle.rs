@@ -0,0 +1,317 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Connection lifecycle state machine.
+//!
+//! [`Lifecycle`] tracks one endpoint's view of the initialization handshake
+//! described in the specification:
+//!
+//! 1. the client sends `initialize`,
+//! 2. the server answers with an [`InitializeResult`],
+//! 3. the client sends `notifications/initialized`,
+//! 4. both sides operate normally until the connection is shut down.
+//!
+//! Every message sent or received is fed through [`Lifecycle::on_outbound`]
+//! or [`Lifecycle::on_inbound`], which reject traffic the current phase does
+//! not allow. Until the handshake completes only `ping` (and, from the
+//! server, `notifications/message` log messages) may be exchanged.
+
+use crate::errors::McpError;
+use crate::logging::LogEntry;
+use crate::protocol::{
+    InitializeParams, InitializeResult, InitializedNotification, JsonRpcMessage, JsonRpcRequest,
+    McpNotification, McpRequest, PingRequest, RequestId, ResponseOutcome, Side,
+};
+use crate::version::ProtocolVersion;
+
+/// Phase of the connection lifecycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleState {
+    /// Nothing but pings exchanged yet
+    Uninitialized,
+    /// `initialize` sent, awaiting its response
+    Initializing {
+        /// Id of the `initialize` request
+        request_id: RequestId,
+    },
+    /// `initialize` answered, awaiting `notifications/initialized`
+    Initialized,
+    /// Handshake complete, normal operation
+    Operating,
+    /// Connection closed; no further traffic is allowed
+    ShutDown,
+}
+
+/// Lifecycle of one connection, seen from one endpoint
+#[derive(Debug, Clone)]
+pub struct Lifecycle {
+    side: Side,
+    state: LifecycleState,
+    params: Option<InitializeParams>,
+    result: Option<InitializeResult>,
+}
+
+impl Lifecycle {
+    /// Create the lifecycle of an endpoint playing `side`
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            state: LifecycleState::Uninitialized,
+            params: None,
+            result: None,
+        }
+    }
+
+    /// Side of this endpoint
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Current phase
+    pub fn state(&self) -> &LifecycleState {
+        &self.state
+    }
+
+    /// Whether the handshake has completed and the connection is open
+    pub fn is_operating(&self) -> bool {
+        self.state == LifecycleState::Operating
+    }
+
+    /// Parameters of the `initialize` request, once sent
+    pub fn initialize_params(&self) -> Option<&InitializeParams> {
+        self.params.as_ref()
+    }
+
+    /// Result of the `initialize` request, once answered
+    pub fn initialize_result(&self) -> Option<&InitializeResult> {
+        self.result.as_ref()
+    }
+
+    /// Protocol version agreed in the initialize result, once answered
+    ///
+    /// `None` until then, or if the server chose a revision this crate does
+    /// not know.
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        self.result.as_ref()?.protocol_version.parse().ok()
+    }
+
+    /// Validate and record a message this endpoint is about to send
+    pub fn on_outbound(&mut self, message: &JsonRpcMessage) -> Result<(), McpError> {
+        self.on_message(self.side, message)
+    }
+
+    /// Validate and record a message this endpoint has received
+    pub fn on_inbound(&mut self, message: &JsonRpcMessage) -> Result<(), McpError> {
+        self.on_message(self.side.peer(), message)
+    }
+
+    /// Mark the connection as closed
+    pub fn shutdown(&mut self) {
+        self.state = LifecycleState::ShutDown;
+    }
+
+    fn on_message(&mut self, sender: Side, message: &JsonRpcMessage) -> Result<(), McpError> {
+        if self.state == LifecycleState::ShutDown {
+            return Err(McpError::invalid_request("Connection has been shut down"));
+        }
+
+        match message {
+            JsonRpcMessage::Request(request) => self.on_request(sender, request),
+            JsonRpcMessage::Notification(notification) => {
+                self.on_notification(sender, &notification.method)
+            }
+            JsonRpcMessage::Response(_) | JsonRpcMessage::Error(_) => {
+                self.on_response(sender, message)
+            }
+        }
+    }
+
+    fn on_request(&mut self, sender: Side, request: &JsonRpcRequest) -> Result<(), McpError> {
+        if request.method == InitializeParams::METHOD {
+            if sender != Side::Client {
+                return Err(McpError::invalid_request(
+                    "Only the client may send initialize",
+                ));
+            }
+            if self.state != LifecycleState::Uninitialized {
+                return Err(McpError::invalid_request(
+                    "Connection is already initialized",
+                ));
+            }
+            self.params = Some(InitializeParams::from_request(request)?);
+            self.state = LifecycleState::Initializing {
+                request_id: request.id.clone(),
+            };
+            return Ok(());
+        }
+
+        if self.state == LifecycleState::Operating || request.method == PingRequest::METHOD {
+            return Ok(());
+        }
+        Err(McpError::invalid_request(format!(
+            "{} is not allowed before initialization completes",
+            request.method
+        )))
+    }
+
+    fn on_notification(&mut self, sender: Side, method: &str) -> Result<(), McpError> {
+        if method == InitializedNotification::METHOD {
+            if sender != Side::Client {
+                return Err(McpError::invalid_request(
+                    "Only the client may send notifications/initialized",
+                ));
+            }
+            if self.state != LifecycleState::Initialized {
+                return Err(McpError::invalid_request(
+                    "notifications/initialized must follow the initialize response",
+                ));
+            }
+            self.state = LifecycleState::Operating;
+            return Ok(());
+        }
+
+        let is_server_log = sender == Side::Server && method == LogEntry::METHOD;
+        if self.state == LifecycleState::Operating || is_server_log {
+            return Ok(());
+        }
+        Err(McpError::invalid_request(format!(
+            "{} is not allowed before initialization completes",
+            method
+        )))
+    }
+
+    fn on_response(&mut self, sender: Side, message: &JsonRpcMessage) -> Result<(), McpError> {
+        let LifecycleState::Initializing { request_id } = &self.state else {
+            return Ok(());
+        };
+        if sender != Side::Server || message.id() != Some(request_id) {
+            return Ok(());
+        }
+
+        let response = message
+            .clone()
+            .into_response()
+            .ok_or_else(|| McpError::internal_error("Expected a response"))?;
+        match response.outcome {
+            ResponseOutcome::Result(_) => {
+                self.result = Some(response.into_result()?);
+                self.state = LifecycleState::Initialized;
+            }
+            ResponseOutcome::Error(_) => {
+                self.params = None;
+                self.state = LifecycleState::Uninitialized;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{
+        ClientCapabilities, Implementation, JsonRpcNotification, JsonRpcResponse,
+        ServerCapabilities,
+    };
+
+    fn initialize() -> JsonRpcMessage {
+        let params = InitializeParams {
+            protocol_version: ProtocolVersion::LATEST.to_string(),
+            capabilities: ClientCapabilities::default(),
+            client_info: Implementation::new("client", "1.0.0"),
+            meta: None,
+        };
+        params.into_request(RequestId::Number(1)).unwrap().into()
+    }
+
+    fn initialize_result() -> JsonRpcMessage {
+        let result = InitializeResult {
+            protocol_version: ProtocolVersion::LATEST.to_string(),
+            capabilities: ServerCapabilities::default(),
+            server_info: Implementation::new("server", "1.0.0"),
+            instructions: None,
+            meta: None,
+        };
+        JsonRpcResponse::from_result(RequestId::Number(1), Ok(result)).into()
+    }
+
+    fn request(id: i64, method: &str) -> JsonRpcMessage {
+        JsonRpcRequest::new(RequestId::Number(id), method).into()
+    }
+
+    fn notification(method: &str) -> JsonRpcMessage {
+        JsonRpcNotification::new(method).into()
+    }
+
+    #[test]
+    fn test_server_handshake() {
+        let mut server = Lifecycle::new(Side::Server);
+        assert!(server.on_inbound(&request(5, "tools/call")).is_err());
+
+        server.on_inbound(&initialize()).unwrap();
+        assert!(matches!(
+            server.state(),
+            LifecycleState::Initializing { .. }
+        ));
+        assert!(server.on_inbound(&request(2, "tools/list")).is_err());
+        server.on_inbound(&request(3, "ping")).unwrap();
+        server.on_outbound(&notification(LogEntry::METHOD)).unwrap();
+
+        server.on_outbound(&initialize_result()).unwrap();
+        assert_eq!(server.state(), &LifecycleState::Initialized);
+        assert!(server
+            .on_outbound(&request(4, "sampling/createMessage"))
+            .is_err());
+
+        server
+            .on_inbound(&notification(InitializedNotification::METHOD))
+            .unwrap();
+        assert!(server.is_operating());
+        server.on_inbound(&request(5, "tools/call")).unwrap();
+        assert_eq!(
+            server.initialize_params().unwrap().client_info.name,
+            "client"
+        );
+    }
+
+    #[test]
+    fn test_rejects_out_of_order_traffic() {
+        let mut client = Lifecycle::new(Side::Client);
+        client.on_outbound(&initialize()).unwrap();
+        assert!(client.on_outbound(&initialize()).is_err());
+        assert!(client
+            .on_outbound(&notification(InitializedNotification::METHOD))
+            .is_err());
+
+        assert_eq!(client.protocol_version(), None);
+        client.on_inbound(&initialize_result()).unwrap();
+        assert_eq!(client.protocol_version(), Some(ProtocolVersion::LATEST));
+        assert_eq!(
+            client.initialize_result().unwrap().server_info.name,
+            "server"
+        );
+        client
+            .on_outbound(&notification(InitializedNotification::METHOD))
+            .unwrap();
+        assert!(client.on_outbound(&initialize()).is_err());
+        assert!(client
+            .on_inbound(&notification(InitializedNotification::METHOD))
+            .is_err());
+
+        client.shutdown();
+        assert!(client.on_outbound(&request(9, "ping")).is_err());
+    }
+
+    #[test]
+    fn test_failed_initialize_can_retry() {
+        let mut client = Lifecycle::new(Side::Client);
+        client.on_outbound(&initialize()).unwrap();
+        let error = JsonRpcResponse::error(
+            RequestId::Number(1),
+            McpError::invalid_params("Unsupported protocol version"),
+        );
+        client.on_inbound(&error.into()).unwrap();
+        assert_eq!(client.state(), &LifecycleState::Uninitialized);
+        client.on_outbound(&initialize()).unwrap();
+    }
+}
@@ -1,5 +1,7 @@
 //! Logging and debugging support.
 
+use crate::meta::Meta;
+use crate::protocol::{EmptyResult, McpNotification, McpRequest};
 use serde::{Deserialize, Serialize};
 
 /// Logging level enumeration
@@ -24,7 +26,7 @@ pub enum LoggingLevel {
     Emergency,
 }
 
-/// Structured log entry
+/// Structured log entry, the params of `notifications/message`
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogEntry {
     /// Log level
@@ -34,6 +36,10 @@ pub struct LogEntry {
     /// Optional logger name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logger: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Request to set logging level
@@ -41,6 +47,19 @@ pub struct LogEntry {
 pub struct SetLoggingLevelRequest {
     /// Desired logging level
     pub level: LoggingLevel,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpRequest for SetLoggingLevelRequest {
+    const METHOD: &'static str = "logging/setLevel";
+    type Result = EmptyResult;
+}
+
+impl McpNotification for LogEntry {
+    const METHOD: &'static str = "notifications/message";
 }
 
 impl LogEntry {
@@ -50,6 +69,7 @@ impl LogEntry {
             level,
             data,
             logger: None,
+            meta: None,
         }
     }
 
@@ -59,6 +79,7 @@ impl LogEntry {
             level,
             data,
             logger: Some(logger.into()),
+            meta: None,
         }
     }
 }
\ No newline at end of file
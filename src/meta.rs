@@ -0,0 +1,234 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! The `_meta` field carried by requests, results and notifications.
+//!
+//! `_meta` is an open JSON object for out-of-band data such as tracing
+//! context. [`Meta`] preserves whatever a peer sent, unchanged, and
+//! validates keys only when they are inserted locally.
+//!
+//! Keys have the form `[prefix/]name`. The prefix is a series of dot
+//! separated labels; prefixes in which any label but the last is
+//! `modelcontextprotocol` or `mcp` (e.g. `modelcontextprotocol.io/`,
+//! `mcp.dev/`) are reserved for the protocol itself.
+
+use crate::errors::McpError;
+use crate::progress::ProgressToken;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Key under which a request asks for progress notifications
+const PROGRESS_TOKEN: &str = "progressToken";
+
+/// Labels that make a key prefix reserved for MCP
+const RESERVED_LABELS: &[&str] = &["modelcontextprotocol", "mcp"];
+
+/// Contents of a `_meta` field
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Meta(Map<String, Value>);
+
+impl Meta {
+    /// Create an empty `_meta` object
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value stored under `key`
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Store `value` under `key`, returning the previous value
+    ///
+    /// Fails if the key is malformed or uses a reserved prefix.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: Value,
+    ) -> Result<Option<Value>, McpError> {
+        let key = key.into();
+        validate_key(&key)?;
+        Ok(self.0.insert(key, value))
+    }
+
+    /// Add `value` under `key`, builder style
+    pub fn with(mut self, key: impl Into<String>, value: Value) -> Result<Self, McpError> {
+        self.insert(key, value)?;
+        Ok(self)
+    }
+
+    /// Remove the value stored under `key`
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    /// Progress token requested by a request's `_meta`, if present and valid
+    pub fn progress_token(&self) -> Option<ProgressToken> {
+        serde_json::from_value(self.0.get(PROGRESS_TOKEN)?.clone()).ok()
+    }
+
+    /// Ask for progress notifications using `token`
+    pub fn set_progress_token(&mut self, token: ProgressToken) {
+        let token = match token {
+            ProgressToken::String(token) => Value::String(token),
+            ProgressToken::Number(token) => Value::from(token),
+        };
+        self.0.insert(PROGRESS_TOKEN.to_string(), token);
+    }
+
+    /// Check every key, e.g. before forwarding `_meta` received from a user
+    pub fn validate(&self) -> Result<(), McpError> {
+        self.0
+            .keys()
+            .filter(|key| key.as_str() != PROGRESS_TOKEN)
+            .try_for_each(|key| validate_key(key))
+    }
+
+    /// Iterate over the entries
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Map<String, Value>> for Meta {
+    fn from(map: Map<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<Meta> for Map<String, Value> {
+    fn from(meta: Meta) -> Self {
+        meta.0
+    }
+}
+
+/// Whether `key` uses a prefix reserved for MCP
+pub fn is_reserved_key(key: &str) -> bool {
+    let Some((prefix, _)) = key.split_once('/') else {
+        return false;
+    };
+    let labels: Vec<&str> = prefix.split('.').collect();
+    labels[..labels.len() - 1]
+        .iter()
+        .any(|label| RESERVED_LABELS.contains(label))
+}
+
+/// Check that `key` is well formed and not reserved for MCP
+pub fn validate_key(key: &str) -> Result<(), McpError> {
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+
+    if let Some(prefix) = prefix {
+        if !prefix.split('.').all(is_valid_label) {
+            return Err(McpError::invalid_params(format!(
+                "Invalid _meta key prefix: {}",
+                key
+            )));
+        }
+        if is_reserved_key(key) {
+            return Err(McpError::invalid_params(format!(
+                "_meta key prefix is reserved for MCP: {}",
+                key
+            )));
+        }
+    }
+
+    if !is_valid_name(name) {
+        return Err(McpError::invalid_params(format!(
+            "Invalid _meta key name: {}",
+            key
+        )));
+    }
+    Ok(())
+}
+
+/// Prefix label: starts with a letter, ends with a letter or digit,
+/// hyphens allowed in between
+fn is_valid_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => {
+            first.is_ascii_alphabetic()
+                && last.is_ascii_alphanumeric()
+                && bytes
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        }
+        _ => false,
+    }
+}
+
+/// Key name: empty, or starting and ending with an alphanumeric character
+/// with hyphens, underscores and dots allowed in between
+fn is_valid_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => {
+            first.is_ascii_alphanumeric()
+                && last.is_ascii_alphanumeric()
+                && bytes
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_validation() {
+        assert!(validate_key("traceparent").is_ok());
+        assert!(validate_key("example.com/trace-id").is_ok());
+        assert!(validate_key("example.com/").is_ok());
+
+        assert!(validate_key("modelcontextprotocol.io/anything").is_err());
+        assert!(validate_key("mcp.dev/anything").is_err());
+        assert!(validate_key("api.modelcontextprotocol.org/x").is_err());
+        assert!(validate_key("-bad").is_err());
+        assert!(validate_key("1bad.com/x").is_err());
+        assert!(validate_key("trailing_").is_err());
+
+        assert!(is_reserved_key("tools.mcp.com/x"));
+        assert!(!is_reserved_key("mcp/x"));
+        assert!(!is_reserved_key("example.mcp/x"));
+    }
+
+    #[test]
+    fn test_meta_round_trip_is_lossless() {
+        let json = json!({
+            "progressToken": 3,
+            "modelcontextprotocol.io/internal": {"nested": [1, 2]},
+            "example.com/trace": "abc"
+        });
+        let meta: Meta = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&meta).unwrap(), json);
+        assert_eq!(meta.progress_token(), Some(ProgressToken::Number(3)));
+        assert!(meta.validate().is_err());
+    }
+
+    #[test]
+    fn test_meta_insert() {
+        let mut meta = Meta::new().with("example.com/trace", json!("abc")).unwrap();
+        assert!(meta.insert("mcp.dev/x", json!(1)).is_err());
+        meta.set_progress_token(ProgressToken::from("p"));
+        assert_eq!(meta.len(), 2);
+        assert!(meta.validate().is_ok());
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Display metadata shared by tools, prompts, resources and implementations.
+//!
+//! Every listed object has a programmatic `name` and may carry a
+//! human-readable `title`; [`BaseMetadata::display_name`] picks the one a UI
+//! should show. Objects may also advertise [`Icon`]s.
+
+use crate::errors::McpError;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Name and optional title of a listed object
+pub trait BaseMetadata {
+    /// Programmatic identifier
+    fn name(&self) -> &str;
+
+    /// Human-readable title, if any
+    fn title(&self) -> Option<&str>;
+
+    /// Name to show in a UI: the title if there is one, else the name
+    fn display_name(&self) -> &str {
+        self.title().unwrap_or_else(|| self.name())
+    }
+}
+
+/// Icon a client may show for an object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Icon {
+    /// URI of the image; see [`Icon::validate`] for the schemes to fetch
+    pub src: String,
+    /// MIME type, if the URI does not make it clear
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+    /// Sizes the image is available in (e.g. "48x48", "any")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sizes: Vec<String>,
+}
+
+impl Icon {
+    /// Create an icon from an `https:` or `data:` URI
+    ///
+    /// Fails if `src` is not a valid URI with one of those schemes.
+    pub fn new(src: impl Into<String>) -> Result<Self, McpError> {
+        let src = src.into();
+        check_src(&src)?;
+        Ok(Self {
+            src,
+            mime_type: None,
+            sizes: Vec::new(),
+        })
+    }
+
+    /// Set the MIME type
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Add a size the image is available in
+    pub fn with_size(mut self, size: impl Into<String>) -> Self {
+        self.sizes.push(size.into());
+        self
+    }
+
+    /// Fail unless `src` is a valid `https:` or `data:` URI
+    ///
+    /// Icons are accepted with any URI when parsed; clients should skip
+    /// those that fail this check rather than fetch them.
+    pub fn validate(&self) -> Result<(), McpError> {
+        check_src(&self.src)
+    }
+}
+
+/// Fail unless `src` is a valid `https:` or `data:` URI
+fn check_src(src: &str) -> Result<(), McpError> {
+    let url = Url::parse(src)
+        .map_err(|e| McpError::invalid_params(format!("Invalid icon URI {}: {}", src, e)))?;
+    match url.scheme() {
+        "https" | "data" => Ok(()),
+        _ => Err(McpError::invalid_params(format!(
+            "Icon URI must use the https or data scheme: {}",
+            src
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::Implementation;
+    use crate::resources::{Resource, ResourceLink};
+    use crate::tools::{Tool, ToolAnnotations};
+    use serde_json::json;
+
+    #[test]
+    fn test_icon_uri_validation() {
+        assert!(Icon::new("https://example.com/icon.png").is_ok());
+        assert!(Icon::new("data:image/png;base64,aGk=").is_ok());
+        assert!(Icon::new("javascript:alert(1)").is_err());
+        assert!(Icon::new("file:///etc/icon.png").is_err());
+        assert!(Icon::new("icon.png").is_err());
+
+        let icon = Icon::new("https://example.com/icon.svg")
+            .unwrap()
+            .with_mime_type("image/svg+xml")
+            .with_size("any");
+        let json = serde_json::to_value(&icon).unwrap();
+        assert_eq!(
+            json,
+            json!({"src": "https://example.com/icon.svg", "mimeType": "image/svg+xml", "sizes": ["any"]})
+        );
+        assert_eq!(serde_json::from_value::<Icon>(json).unwrap(), icon);
+        assert!(icon.validate().is_ok());
+
+        let insecure: Icon =
+            serde_json::from_value(json!({"src": "http://example.com/a.png"})).unwrap();
+        assert!(insecure.validate().is_err());
+        let server: Implementation = serde_json::from_value(json!({
+            "name": "weather-server",
+            "version": "1.0.0",
+            "icons": [{"src": "http://example.com/a.png"}, {"src": "https://example.com/b.png"}]
+        }))
+        .unwrap();
+        let fetchable: Vec<_> = server
+            .icons
+            .iter()
+            .filter(|icon| icon.validate().is_ok())
+            .collect();
+        assert_eq!(fetchable.len(), 1);
+    }
+
+    #[test]
+    fn test_display_name() {
+        let server = Implementation::new("weather-server", "1.0.0");
+        assert_eq!(server.display_name(), "weather-server");
+        assert_eq!(server.with_title("Weather").display_name(), "Weather");
+
+        let tool = Tool::new("get_forecast", "Get the forecast")
+            .with_annotations(ToolAnnotations::new().with_title("Forecast (hint)"));
+        assert_eq!(tool.display_name(), "Forecast (hint)");
+        assert_eq!(tool.with_title("Forecast").display_name(), "Forecast");
+
+        let resource: Resource =
+            serde_json::from_value(json!({"uri": "file:///big.bin", "size": 2147483648u64}))
+                .unwrap();
+        assert_eq!(resource.display_name(), "file:///big.bin");
+        assert_eq!(resource.size, Some(2_147_483_648));
+        assert_eq!(ResourceLink::from(&resource).size, resource.size);
+    }
+}
@@ -0,0 +1,399 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Correlation of responses with outstanding requests.
+//!
+//! [`PendingRequests`] is the bookkeeping every client and server needs to
+//! match an incoming `JsonRpcResponse` to the request it answers. It performs
+//! no I/O and never reads the clock: callers pass `now` explicitly, which
+//! keeps it usable from any transport and testable without timers.
+
+use crate::errors::McpError;
+use crate::protocol::{JsonRpcRequest, JsonRpcResponse, McpRequest, RequestId, ResponseOutcome};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Number of completed ids remembered to tell duplicate responses from unknown ones
+const DEFAULT_HISTORY: usize = 256;
+
+/// Outstanding request awaiting its response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingRequest {
+    /// Method of the request
+    pub method: String,
+    /// When the request was sent
+    pub sent_at: Instant,
+    /// When the request should be given up on, if ever
+    pub deadline: Option<Instant>,
+}
+
+/// Response matched to the request it answers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedResponse {
+    /// Request identifier
+    pub id: RequestId,
+    /// The request as it was recorded
+    pub request: PendingRequest,
+    /// Result or error carried by the response
+    pub outcome: ResponseOutcome,
+    /// Time between sending the request and receiving the response
+    pub elapsed: Duration,
+}
+
+/// Request that passed its deadline without a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredRequest {
+    /// Request identifier
+    pub id: RequestId,
+    /// The request as it was recorded
+    pub request: PendingRequest,
+}
+
+/// Ways a request or response can fail to correlate
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CorrelationError {
+    /// Requests must carry a non-null id
+    #[error("Request id must not be null")]
+    NullId,
+    /// Another request with the same id is still pending
+    #[error("Request id {0} is already pending")]
+    IdInUse(RequestId),
+    /// The response answers a request that was already answered
+    #[error("Duplicate response for request {0}")]
+    Duplicate(RequestId),
+    /// The response arrived after its request expired or was removed
+    #[error("Response for request {0} arrived after it was abandoned")]
+    Late(RequestId),
+    /// No request with this id was ever recorded (or it was forgotten)
+    #[error("No pending request with id {0}")]
+    Unknown(RequestId),
+}
+
+/// How a request left the pending table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Completion {
+    Answered,
+    Expired,
+}
+
+/// Table of requests awaiting a response, keyed by request id
+#[derive(Debug, Clone)]
+pub struct PendingRequests {
+    pending: HashMap<RequestId, PendingRequest>,
+    default_timeout: Option<Duration>,
+    completed: HashMap<RequestId, Completion>,
+    completed_order: VecDeque<RequestId>,
+    history: usize,
+}
+
+impl PendingRequests {
+    /// Create a table whose requests never expire unless given a deadline
+    pub fn new() -> Self {
+        Self {
+            pending: HashMap::new(),
+            default_timeout: None,
+            completed: HashMap::new(),
+            completed_order: VecDeque::new(),
+            history: DEFAULT_HISTORY,
+        }
+    }
+
+    /// Create a table applying `timeout` to every request recorded with [`insert`](Self::insert)
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            default_timeout: Some(timeout),
+            ..Self::new()
+        }
+    }
+
+    /// Set how many completed ids are remembered for duplicate detection
+    pub fn with_history(mut self, history: usize) -> Self {
+        self.history = history;
+        self.trim_history();
+        self
+    }
+
+    /// Record a request sent at `now`, using the table's default timeout
+    pub fn insert(
+        &mut self,
+        request: &JsonRpcRequest,
+        now: Instant,
+    ) -> Result<(), CorrelationError> {
+        let deadline = self.default_timeout.map(|timeout| now + timeout);
+        self.insert_with_deadline(request, now, deadline)
+    }
+
+    /// Record a request sent at `now` with an explicit deadline
+    pub fn insert_with_deadline(
+        &mut self,
+        request: &JsonRpcRequest,
+        now: Instant,
+        deadline: Option<Instant>,
+    ) -> Result<(), CorrelationError> {
+        if request.id.is_null() {
+            return Err(CorrelationError::NullId);
+        }
+        if self.pending.contains_key(&request.id) {
+            return Err(CorrelationError::IdInUse(request.id.clone()));
+        }
+        if self.completed.remove(&request.id).is_some() {
+            self.completed_order.retain(|id| id != &request.id);
+        }
+        self.pending.insert(
+            request.id.clone(),
+            PendingRequest {
+                method: request.method.clone(),
+                sent_at: now,
+                deadline,
+            },
+        );
+        Ok(())
+    }
+
+    /// Match a response received at `now` to its pending request
+    pub fn resolve(
+        &mut self,
+        response: JsonRpcResponse,
+        now: Instant,
+    ) -> Result<ResolvedResponse, CorrelationError> {
+        let Some(request) = self.pending.remove(&response.id) else {
+            return Err(match self.completed.get(&response.id) {
+                Some(Completion::Answered) => CorrelationError::Duplicate(response.id),
+                Some(Completion::Expired) => CorrelationError::Late(response.id),
+                None => CorrelationError::Unknown(response.id),
+            });
+        };
+        self.complete(response.id.clone(), Completion::Answered);
+        Ok(ResolvedResponse {
+            elapsed: now.saturating_duration_since(request.sent_at),
+            id: response.id,
+            request,
+            outcome: response.outcome,
+        })
+    }
+
+    /// Remove a pending request without a response, e.g. after cancelling it
+    ///
+    /// A response arriving later is reported as [`CorrelationError::Late`].
+    pub fn remove(&mut self, id: &RequestId) -> Option<PendingRequest> {
+        let request = self.pending.remove(id)?;
+        self.complete(id.clone(), Completion::Expired);
+        Some(request)
+    }
+
+    /// Remove and return every request whose deadline is at or before `now`
+    pub fn expire(&mut self, now: Instant) -> Vec<ExpiredRequest> {
+        let ids: Vec<RequestId> = self
+            .pending
+            .iter()
+            .filter(|(_, request)| request.deadline.is_some_and(|deadline| deadline <= now))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let mut expired: Vec<ExpiredRequest> = ids
+            .into_iter()
+            .filter_map(|id| {
+                let request = self.remove(&id)?;
+                Some(ExpiredRequest { id, request })
+            })
+            .collect();
+        expired.sort_by(|a, b| a.id.cmp(&b.id));
+        expired
+    }
+
+    /// Earliest deadline among pending requests, for scheduling the next [`expire`](Self::expire)
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending
+            .values()
+            .filter_map(|request| request.deadline)
+            .min()
+    }
+
+    /// Pending request with the given id
+    pub fn get(&self, id: &RequestId) -> Option<&PendingRequest> {
+        self.pending.get(id)
+    }
+
+    /// Whether a request with the given id is pending
+    pub fn contains(&self, id: &RequestId) -> bool {
+        self.pending.contains_key(id)
+    }
+
+    /// Number of pending requests
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether no requests are pending
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    fn complete(&mut self, id: RequestId, completion: Completion) {
+        if self.completed.insert(id.clone(), completion).is_none() {
+            self.completed_order.push_back(id);
+        }
+        self.trim_history();
+    }
+
+    fn trim_history(&mut self) {
+        while self.completed_order.len() > self.history {
+            if let Some(id) = self.completed_order.pop_front() {
+                self.completed.remove(&id);
+            }
+        }
+    }
+}
+
+impl Default for PendingRequests {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResolvedResponse {
+    /// Decode the outcome as the result of request type `R`
+    ///
+    /// Fails with an internal error if the recorded method is not `R::METHOD`.
+    pub fn into_result<R: McpRequest>(self) -> Result<R::Result, McpError> {
+        if self.request.method != R::METHOD {
+            return Err(McpError::internal_error(format!(
+                "Request {} was {}, not {}",
+                self.id,
+                self.request.method,
+                R::METHOD
+            )));
+        }
+        self.outcome.into_result()
+    }
+}
+
+impl From<CorrelationError> for McpError {
+    fn from(error: CorrelationError) -> Self {
+        McpError::invalid_request(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{EmptyResult, PingRequest};
+    use crate::tools::ListToolsRequest;
+    use serde_json::json;
+
+    fn request(id: i64, method: &str) -> JsonRpcRequest {
+        JsonRpcRequest::new(RequestId::Number(id), method)
+    }
+
+    #[test]
+    fn test_resolve_typed_result() {
+        let start = Instant::now();
+        let mut table = PendingRequests::new();
+        table.insert(&request(1, "ping"), start).unwrap();
+        assert!(table.contains(&RequestId::Number(1)));
+
+        let response = JsonRpcResponse::success(RequestId::Number(1), json!({}));
+        let resolved = table
+            .resolve(response, start + Duration::from_millis(5))
+            .unwrap();
+        assert_eq!(resolved.elapsed, Duration::from_millis(5));
+        assert!(table.is_empty());
+
+        let mismatch = resolved.clone().into_result::<ListToolsRequest>();
+        assert!(mismatch.is_err());
+        assert_eq!(
+            resolved.into_result::<PingRequest>().unwrap(),
+            EmptyResult::default()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_and_unknown_ids() {
+        let now = Instant::now();
+        let mut table = PendingRequests::new();
+        table.insert(&request(1, "ping"), now).unwrap();
+        assert_eq!(
+            table.insert(&request(1, "ping"), now),
+            Err(CorrelationError::IdInUse(RequestId::Number(1)))
+        );
+        assert_eq!(
+            table.insert(&JsonRpcRequest::new(RequestId::Null, "ping"), now),
+            Err(CorrelationError::NullId)
+        );
+
+        let response = JsonRpcResponse::success(RequestId::Number(1), json!({}));
+        table.resolve(response.clone(), now).unwrap();
+        assert_eq!(
+            table.resolve(response, now),
+            Err(CorrelationError::Duplicate(RequestId::Number(1)))
+        );
+
+        let stray = JsonRpcResponse::success(RequestId::Number(9), json!({}));
+        assert_eq!(
+            table.resolve(stray, now),
+            Err(CorrelationError::Unknown(RequestId::Number(9)))
+        );
+    }
+
+    #[test]
+    fn test_expire_past_deadline() {
+        let start = Instant::now();
+        let mut table = PendingRequests::with_timeout(Duration::from_secs(10));
+        table.insert(&request(1, "tools/list"), start).unwrap();
+        table
+            .insert(&request(2, "tools/call"), start + Duration::from_secs(5))
+            .unwrap();
+        table
+            .insert_with_deadline(&request(3, "ping"), start, None)
+            .unwrap();
+        assert_eq!(table.next_deadline(), Some(start + Duration::from_secs(10)));
+
+        assert!(table.expire(start + Duration::from_secs(9)).is_empty());
+        let expired = table.expire(start + Duration::from_secs(12));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, RequestId::Number(1));
+        assert_eq!(expired[0].request.method, "tools/list");
+        assert_eq!(table.len(), 2);
+
+        let late = JsonRpcResponse::success(RequestId::Number(1), json!({}));
+        assert_eq!(
+            table.resolve(late, start + Duration::from_secs(13)),
+            Err(CorrelationError::Late(RequestId::Number(1)))
+        );
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let now = Instant::now();
+        let mut table = PendingRequests::new().with_history(1);
+        for id in 1..=2 {
+            table.insert(&request(id, "ping"), now).unwrap();
+            let response = JsonRpcResponse::success(RequestId::Number(id), json!({}));
+            table.resolve(response, now).unwrap();
+        }
+        let first = JsonRpcResponse::success(RequestId::Number(1), json!({}));
+        assert_eq!(
+            table.resolve(first, now),
+            Err(CorrelationError::Unknown(RequestId::Number(1)))
+        );
+    }
+
+    #[test]
+    fn test_reused_id_does_not_evict_history() {
+        let now = Instant::now();
+        let mut table = PendingRequests::new().with_history(2);
+        for id in [1, 2, 1] {
+            table.insert(&request(id, "ping"), now).unwrap();
+            let response = JsonRpcResponse::success(RequestId::Number(id), json!({}));
+            table.resolve(response, now).unwrap();
+        }
+        for id in [1, 2] {
+            let response = JsonRpcResponse::success(RequestId::Number(id), json!({}));
+            assert_eq!(
+                table.resolve(response, now),
+                Err(CorrelationError::Duplicate(RequestId::Number(id)))
+            );
+        }
+    }
+}
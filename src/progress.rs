@@ -0,0 +1,320 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Progress reporting for long-running requests (`notifications/progress`).
+//!
+//! The sender of a request opts in by putting a [`ProgressToken`] in the
+//! request's `params._meta.progressToken`; the receiver then sends
+//! [`ProgressNotification`]s carrying that token until it responds.
+
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::protocol::{JsonRpcRequest, McpNotification};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Token associating progress notifications with a request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ProgressToken {
+    /// String token
+    String(String),
+    /// Numeric token
+    Number(i64),
+}
+
+/// Progress update for a long-running request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgressNotification {
+    /// Token from the request's `_meta.progressToken`
+    #[serde(rename = "progressToken")]
+    pub progress_token: ProgressToken,
+    /// Progress so far; increases with every notification
+    pub progress: f64,
+    /// Total amount of work, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// Human-readable description of the current step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Tracks progress for every token handed out on outstanding requests
+///
+/// Notifications for unknown tokens, or whose progress does not increase,
+/// are rejected.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressTracker {
+    tokens: HashMap<ProgressToken, Option<f64>>,
+}
+
+impl ProgressNotification {
+    /// Create a progress update
+    pub fn new(progress_token: ProgressToken, progress: f64, total: Option<f64>) -> Self {
+        Self {
+            progress_token,
+            progress,
+            total,
+            message: None,
+            meta: None,
+        }
+    }
+
+    /// Attach a human-readable message
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+impl McpNotification for ProgressNotification {
+    const METHOD: &'static str = "notifications/progress";
+}
+
+impl ProgressTracker {
+    /// Create an empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start accepting progress for `token`
+    ///
+    /// Fails if the token is already in use by another outstanding request.
+    pub fn register(&mut self, token: ProgressToken) -> Result<(), McpError> {
+        if self.tokens.contains_key(&token) {
+            return Err(McpError::invalid_request(format!(
+                "Progress token {} is already in use",
+                token
+            )));
+        }
+        self.tokens.insert(token, None);
+        Ok(())
+    }
+
+    /// Record a progress notification
+    ///
+    /// Fails for unknown tokens, for non-finite `progress` or `total`, and for
+    /// progress that does not exceed the previously reported value.
+    pub fn update(&mut self, notification: &ProgressNotification) -> Result<(), McpError> {
+        let total_is_finite = notification.total.map_or(true, f64::is_finite);
+        if !notification.progress.is_finite() || !total_is_finite {
+            return Err(McpError::invalid_params(format!(
+                "Progress for token {} is not a finite number",
+                notification.progress_token
+            )));
+        }
+        let last = self
+            .tokens
+            .get_mut(&notification.progress_token)
+            .ok_or_else(|| {
+                McpError::invalid_params(format!(
+                    "Unknown progress token: {}",
+                    notification.progress_token
+                ))
+            })?;
+        if let Some(previous) = *last {
+            if notification.progress <= previous {
+                return Err(McpError::invalid_params(format!(
+                    "Progress for token {} went from {} to {}",
+                    notification.progress_token, previous, notification.progress
+                )));
+            }
+        }
+        *last = Some(notification.progress);
+        Ok(())
+    }
+
+    /// Last progress reported for `token`
+    pub fn progress(&self, token: &ProgressToken) -> Option<f64> {
+        self.tokens.get(token).copied().flatten()
+    }
+
+    /// Stop accepting progress for `token`, once its request has completed
+    pub fn complete(&mut self, token: &ProgressToken) -> bool {
+        self.tokens.remove(token).is_some()
+    }
+
+    /// Whether `token` is being tracked
+    pub fn contains(&self, token: &ProgressToken) -> bool {
+        self.tokens.contains_key(token)
+    }
+}
+
+impl JsonRpcRequest {
+    /// Request progress notifications by setting `params._meta.progressToken`
+    ///
+    /// Missing params are created as an empty object. Fails if `params` or
+    /// `params._meta` exist but are not objects.
+    pub fn with_progress_token(mut self, token: ProgressToken) -> Result<Self, McpError> {
+        let params = self
+            .params
+            .get_or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| McpError::invalid_params("Request params must be an object"))?;
+        let meta = params
+            .entry("_meta")
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| McpError::invalid_params("Request _meta must be an object"))?;
+        let token = serde_json::to_value(token)
+            .map_err(|e| McpError::internal_error(format!("Invalid progress token: {}", e)))?;
+        meta.insert("progressToken".to_string(), token);
+        Ok(self)
+    }
+
+    /// Progress token from `params._meta.progressToken`, if present and valid
+    pub fn progress_token(&self) -> Option<ProgressToken> {
+        let token = self.params.as_ref()?.get("_meta")?.get("progressToken")?;
+        serde_json::from_value(token.clone()).ok()
+    }
+}
+
+impl fmt::Display for ProgressToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(token) => f.write_str(token),
+            Self::Number(token) => write!(f, "{}", token),
+        }
+    }
+}
+
+impl From<i64> for ProgressToken {
+    fn from(token: i64) -> Self {
+        Self::Number(token)
+    }
+}
+
+impl From<String> for ProgressToken {
+    fn from(token: String) -> Self {
+        Self::String(token)
+    }
+}
+
+impl From<&str> for ProgressToken {
+    fn from(token: &str) -> Self {
+        Self::String(token.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::RequestId;
+    use serde_json::json;
+
+    #[test]
+    fn test_attach_progress_token() {
+        let request = JsonRpcRequest::with_params(
+            RequestId::Number(1),
+            "tools/call",
+            json!({"name": "build", "_meta": {"trace": "abc"}}),
+        )
+        .with_progress_token(ProgressToken::from("build-1"))
+        .unwrap();
+        assert_eq!(
+            request.params,
+            Some(json!({
+                "name": "build",
+                "_meta": {"trace": "abc", "progressToken": "build-1"}
+            }))
+        );
+        assert_eq!(
+            request.progress_token(),
+            Some(ProgressToken::from("build-1"))
+        );
+
+        let bare = JsonRpcRequest::new(RequestId::Number(2), "resources/read")
+            .with_progress_token(ProgressToken::from(7))
+            .unwrap();
+        assert_eq!(bare.params, Some(json!({"_meta": {"progressToken": 7}})));
+
+        let array = JsonRpcRequest::with_params(RequestId::Number(3), "x", json!([1]));
+        assert!(array.with_progress_token(ProgressToken::from(1)).is_err());
+    }
+
+    #[test]
+    fn test_progress_notification_wire_format() {
+        let notification = ProgressNotification::new(ProgressToken::from(1), 50.0, Some(100.0))
+            .with_message("Halfway there");
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(
+            json_rpc.params,
+            Some(json!({
+                "progressToken": 1,
+                "progress": 50.0,
+                "total": 100.0,
+                "message": "Halfway there"
+            }))
+        );
+        assert_eq!(
+            ProgressNotification::from_notification(&json_rpc).unwrap(),
+            notification
+        );
+    }
+
+    #[test]
+    fn test_tracker_rejects_regressions_and_unknown_tokens() {
+        let token = ProgressToken::from("t");
+        let mut tracker = ProgressTracker::new();
+        tracker.register(token.clone()).unwrap();
+        assert!(tracker.register(token.clone()).is_err());
+
+        tracker
+            .update(&ProgressNotification::new(token.clone(), 1.0, None))
+            .unwrap();
+        tracker
+            .update(&ProgressNotification::new(token.clone(), 2.5, None))
+            .unwrap();
+        assert_eq!(tracker.progress(&token), Some(2.5));
+        assert!(tracker
+            .update(&ProgressNotification::new(token.clone(), 2.5, None))
+            .is_err());
+        assert!(tracker
+            .update(&ProgressNotification::new(token.clone(), 1.0, None))
+            .is_err());
+
+        let unknown = ProgressNotification::new(ProgressToken::from(9), 1.0, None);
+        assert!(tracker.update(&unknown).is_err());
+
+        assert!(tracker.complete(&token));
+        assert!(tracker
+            .update(&ProgressNotification::new(token, 3.0, None))
+            .is_err());
+    }
+
+    #[test]
+    fn test_tracker_rejects_non_finite_progress() {
+        let token = ProgressToken::from("t");
+        let mut tracker = ProgressTracker::new();
+        tracker.register(token.clone()).unwrap();
+
+        assert!(tracker
+            .update(&ProgressNotification::new(token.clone(), f64::NAN, None))
+            .is_err());
+        assert!(tracker
+            .update(&ProgressNotification::new(
+                token.clone(),
+                1.0,
+                Some(f64::INFINITY)
+            ))
+            .is_err());
+        assert_eq!(tracker.progress(&token), None);
+
+        tracker
+            .update(&ProgressNotification::new(token.clone(), 2.0, Some(4.0)))
+            .unwrap();
+        assert!(tracker
+            .update(&ProgressNotification::new(token.clone(), f64::NAN, None))
+            .is_err());
+        assert!(tracker
+            .update(&ProgressNotification::new(token.clone(), 1.0, None))
+            .is_err());
+        assert_eq!(tracker.progress(&token), Some(2.0));
+    }
+}
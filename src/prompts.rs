@@ -1,5 +1,11 @@
 //! Prompt templates and arguments.
 
+use crate::content::{legacy_resource, no_legacy_equivalent, ContentBlock};
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::metadata::{BaseMetadata, Icon};
+use crate::protocol::{McpNotification, McpRequest};
+use crate::version::ProtocolVersion;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -8,12 +14,18 @@ use serde_json::Value;
 pub struct Prompt {
     /// Prompt name
     pub name: String,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Human-readable description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Prompt arguments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arguments: Option<Vec<PromptArgument>>,
+    /// Icons a client may show
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<Icon>,
 }
 
 /// Prompt argument definition
@@ -30,11 +42,15 @@ pub struct PromptArgument {
 }
 
 /// Request to list available prompts
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListPromptsRequest {
     /// Optional cursor for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Response with list of available prompts
@@ -46,6 +62,10 @@ pub struct ListPromptsResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "nextCursor")]
     pub next_cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Request to get a specific prompt
@@ -56,6 +76,10 @@ pub struct GetPromptRequest {
     /// Prompt arguments
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arguments: Option<Value>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Prompt result with messages
@@ -66,6 +90,10 @@ pub struct GetPromptResult {
     pub description: Option<String>,
     /// Generated messages
     pub messages: Vec<PromptMessage>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Message in a prompt
@@ -74,7 +102,7 @@ pub struct PromptMessage {
     /// Message role
     pub role: PromptRole,
     /// Message content
-    pub content: PromptContent,
+    pub content: ContentBlock,
 }
 
 /// Role of a message in a prompt
@@ -89,7 +117,11 @@ pub enum PromptRole {
     System,
 }
 
-/// Content of a prompt message
+/// Prompt message content as carried before [`ContentBlock`]
+///
+/// Converts into a `ContentBlock`; the conversion back fails for variants
+/// this type cannot hold.
+#[deprecated(note = "use `ContentBlock`, which `PromptMessage` carries")]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum PromptContent {
@@ -114,4 +146,96 @@ pub enum PromptContent {
         /// Resource URI
         resource: String,
     },
-}
\ No newline at end of file
+}
+
+#[allow(deprecated)]
+impl From<PromptContent> for ContentBlock {
+    fn from(content: PromptContent) -> Self {
+        match content {
+            PromptContent::Text { text } => Self::text(text),
+            PromptContent::Image { data, mime_type } => Self::image(data, mime_type),
+            PromptContent::Resource { resource } => legacy_resource(resource),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<ContentBlock> for PromptContent {
+    type Error = McpError;
+
+    fn try_from(content: ContentBlock) -> Result<Self, McpError> {
+        match content {
+            ContentBlock::Text { text, .. } => Ok(Self::Text { text }),
+            ContentBlock::Image {
+                data, mime_type, ..
+            } => Ok(Self::Image { data, mime_type }),
+            ContentBlock::ResourceLink(link) => Ok(Self::Resource { resource: link.uri }),
+            content => Err(no_legacy_equivalent(&content, "PromptContent")),
+        }
+    }
+}
+
+/// Notification that the server's prompt list has changed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PromptListChangedNotification {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpRequest for ListPromptsRequest {
+    const METHOD: &'static str = "prompts/list";
+    type Result = ListPromptsResult;
+}
+
+impl McpRequest for GetPromptRequest {
+    const METHOD: &'static str = "prompts/get";
+    type Result = GetPromptResult;
+}
+
+impl McpNotification for PromptListChangedNotification {
+    const METHOD: &'static str = "notifications/prompts/list_changed";
+}
+
+impl BaseMetadata for Prompt {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+impl GetPromptResult {
+    /// Replace content `version` does not allow with text placeholders
+    pub fn downgrade_for(mut self, version: ProtocolVersion) -> Self {
+        self.messages = self
+            .messages
+            .into_iter()
+            .map(|message| PromptMessage {
+                content: message.content.downgrade_for(version),
+                ..message
+            })
+            .collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_list_changed_round_trip() {
+        let notification = PromptListChangedNotification::default();
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.method, "notifications/prompts/list_changed");
+        assert_eq!(json_rpc.params, None);
+        assert_eq!(
+            PromptListChangedNotification::from_notification(&json_rpc).unwrap(),
+            notification
+        );
+    }
+}
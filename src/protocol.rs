@@ -3,9 +3,15 @@
 //! This module contains the fundamental types for JSON-RPC communication and MCP
 //! protocol negotiation, including requests, responses, capabilities, and metadata.
 
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::metadata::{BaseMetadata, Icon};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::atomic::{AtomicI64, Ordering};
 
 /// JSON-RPC 2.0 request structure
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -22,18 +28,48 @@ pub struct JsonRpcRequest {
 }
 
 /// JSON-RPC 2.0 response structure
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Deserialization rejects responses carrying both `result` and `error`,
+/// or neither of them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct JsonRpcResponse {
     /// JSON-RPC version (always "2.0")
     pub jsonrpc: String,
     /// Request identifier
     pub id: RequestId,
-    /// Response result (success)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<Value>,
-    /// Response error (failure)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<crate::errors::McpError>,
+    /// Response outcome (serialized as `result` or `error`)
+    #[serde(flatten)]
+    pub outcome: ResponseOutcome,
+}
+
+/// Outcome of a request: exactly one of a result or an error
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ResponseOutcome {
+    /// Successful result
+    #[serde(rename = "result")]
+    Result(Value),
+    /// Error describing the failure
+    #[serde(rename = "error")]
+    Error(McpError),
+}
+
+/// Wire shape of a response before the outcome is checked
+#[derive(Deserialize)]
+struct RawJsonRpcResponse {
+    jsonrpc: String,
+    id: RequestId,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<McpError>,
+}
+
+/// Deserialize a member that is present, keeping an explicit `null` as `Some`
+fn deserialize_present<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
 }
 
 /// JSON-RPC notification structure (no response expected)
@@ -48,8 +84,83 @@ pub struct JsonRpcNotification {
     pub params: Option<Value>,
 }
 
-/// Request identifier - can be string, number, or null
+/// JSON-RPC error response structure
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    /// JSON-RPC version (always "2.0")
+    pub jsonrpc: String,
+    /// Request identifier (null if it could not be determined)
+    pub id: RequestId,
+    /// Error details
+    pub error: McpError,
+}
+
+/// Any single JSON-RPC 2.0 message
+///
+/// Deserialization classifies the message by which of `id`, `method`,
+/// `result` and `error` are present, and rejects shapes that match none
+/// or more than one of the variants.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JsonRpcMessage {
+    /// Request expecting a response (`method` and `id`)
+    Request(JsonRpcRequest),
+    /// Notification (`method` without `id`)
+    Notification(JsonRpcNotification),
+    /// Successful response (`id` and `result`)
+    Response(JsonRpcResponse),
+    /// Error response (`id` and `error`)
+    Error(JsonRpcError),
+}
+
+/// Batch of requests and notifications sent as a single JSON array
+///
+/// A batch is never empty and contains only `Request` and `Notification`
+/// messages; request ids within a batch are unique.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct JsonRpcRequestBatch(Vec<JsonRpcMessage>);
+
+/// Batch of responses answering a [`JsonRpcRequestBatch`]
+///
+/// A batch is never empty and contains only `Response` and `Error` messages.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct JsonRpcResponseBatch(Vec<JsonRpcMessage>);
+
+/// Anything that may arrive on the wire: a single message or a batch
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JsonRpcPayload {
+    /// Single message
+    Message(JsonRpcMessage),
+    /// Batch of requests and notifications
+    RequestBatch(JsonRpcRequestBatch),
+    /// Batch of responses
+    ResponseBatch(JsonRpcResponseBatch),
+}
+
+/// Result of matching a response batch against its request batch
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchPairing<'a> {
+    /// Requests together with the response carrying their id
+    pub pairs: Vec<(&'a JsonRpcRequest, &'a JsonRpcMessage)>,
+    /// Requests for which no response was received
+    pub unanswered: Vec<&'a JsonRpcRequest>,
+    /// Responses whose id matches no request (e.g. errors with a null id)
+    pub unmatched: Vec<&'a JsonRpcMessage>,
+}
+
+/// Request identifier - can be string, number, or null
+///
+/// MCP forbids null ids on requests. `Null` is only accepted on error
+/// responses, where the id of the offending message could not be read;
+/// requests and success responses carrying a null id are rejected when
+/// parsed as a [`JsonRpcMessage`].
+///
+/// Ids order strings before numbers before null, so they can key ordered
+/// maps of in-flight requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum RequestId {
     /// String identifier
@@ -60,8 +171,36 @@ pub enum RequestId {
     Null,
 }
 
+/// Source of fresh request identifiers
+///
+/// Generators take `&self` so a single instance can be shared between the
+/// tasks sending requests on one connection.
+pub trait RequestIdGenerator: Send + Sync {
+    /// Produce an identifier not handed out before by this generator
+    fn next_id(&self) -> RequestId;
+}
+
+/// Generator handing out increasing numeric ids
+#[derive(Debug)]
+pub struct SequentialIdGenerator {
+    next: AtomicI64,
+}
+
+/// Generator handing out random UUID v4 string ids
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidIdGenerator;
+
+/// Side of an MCP connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The client, which sends `initialize`
+    Client,
+    /// The server, which answers `initialize`
+    Server,
+}
+
 /// Server capabilities advertised during initialization
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ServerCapabilities {
     /// Tool calling support
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -75,13 +214,16 @@ pub struct ServerCapabilities {
     /// Logging support
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logging: Option<LoggingCapability>,
+    /// Argument completion support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completions: Option<CompletionsCapability>,
     /// Experimental features
     #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental: Option<HashMap<String, Value>>,
 }
 
 /// Client capabilities sent during initialization
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ClientCapabilities {
     /// Resource roots support
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,6 +231,9 @@ pub struct ClientCapabilities {
     /// Sampling support
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sampling: Option<SamplingCapability>,
+    /// Elicitation support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elicitation: Option<ElicitationCapability>,
     /// Experimental features
     #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental: Option<HashMap<String, Value>>,
@@ -99,6 +244,7 @@ pub struct ClientCapabilities {
 pub struct ToolsCapability {
     /// Whether tools support list changes
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listChanged")]
     pub list_changed: Option<bool>,
 }
 
@@ -110,6 +256,7 @@ pub struct ResourcesCapability {
     pub subscribe: Option<bool>,
     /// Whether resources support list changes
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listChanged")]
     pub list_changed: Option<bool>,
 }
 
@@ -118,6 +265,7 @@ pub struct ResourcesCapability {
 pub struct PromptsCapability {
     /// Whether prompts support list changes
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listChanged")]
     pub list_changed: Option<bool>,
 }
 
@@ -125,11 +273,16 @@ pub struct PromptsCapability {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingCapability {}
 
+/// Completions capability configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompletionsCapability {}
+
 /// Roots capability configuration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RootsCapability {
     /// Whether roots support list changes
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listChanged")]
     pub list_changed: Option<bool>,
 }
 
@@ -137,13 +290,23 @@ pub struct RootsCapability {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SamplingCapability {}
 
+/// Elicitation capability configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElicitationCapability {}
+
 /// Implementation metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Implementation {
     /// Implementation name
     pub name: String,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Implementation version
     pub version: String,
+    /// Icons a client may show
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<Icon>,
 }
 
 /// Initialize request parameters
@@ -157,6 +320,10 @@ pub struct InitializeParams {
     /// Client implementation info
     #[serde(rename = "clientInfo")]
     pub client_info: Implementation,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Initialize response result
@@ -173,36 +340,167 @@ pub struct InitializeResult {
     /// Optional instructions for the LLM
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instructions: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Ping request (for connection health checks)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct PingRequest {}
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PingRequest {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
 /// Empty response for various operations
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct EmptyResult {}
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmptyResult {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
 
-impl Default for ServerCapabilities {
-    fn default() -> Self {
-        Self {
-            tools: None,
-            resources: None,
-            prompts: None,
-            logging: None,
-            experimental: None,
+/// Notification sent by the client once it has processed the initialize result
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InitializedNotification {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Request parameters bound to their method name and result type
+///
+/// Implemented by every params struct (e.g. `CallToolRequest`), so typed
+/// requests can be built and their responses decoded without looking up
+/// method strings by hand.
+pub trait McpRequest: Serialize + DeserializeOwned {
+    /// Method name on the wire (e.g. "tools/call")
+    const METHOD: &'static str;
+
+    /// Result type carried by a successful response
+    type Result: Serialize + DeserializeOwned;
+
+    /// Build a JSON-RPC request carrying these parameters
+    fn into_request(self, id: RequestId) -> Result<JsonRpcRequest, McpError> {
+        let params = serde_json::to_value(self)
+            .map_err(|e| McpError::internal_error(format!("Invalid params: {}", e)))?;
+        Ok(JsonRpcRequest::with_params(id, Self::METHOD, params))
+    }
+
+    /// Decode these parameters from a JSON-RPC request
+    ///
+    /// Missing params are decoded from an empty object.
+    fn from_request(request: &JsonRpcRequest) -> Result<Self, McpError> {
+        if request.method != Self::METHOD {
+            return Err(McpError::invalid_request(format!(
+                "Expected method {}, got {}",
+                Self::METHOD,
+                request.method
+            )));
         }
+        let params = request
+            .params
+            .clone()
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        serde_json::from_value(params).map_err(|e| McpError::invalid_params(e.to_string()))
+    }
+
+    /// Decode the result of a response to this request
+    fn decode_result(response: JsonRpcResponse) -> Result<Self::Result, McpError> {
+        response.into_result()
     }
 }
 
-impl Default for ClientCapabilities {
-    fn default() -> Self {
+/// Notification parameters bound to their method name
+///
+/// Implemented by every notification params struct (e.g.
+/// `ProgressNotification`), mirroring [`McpRequest`].
+pub trait McpNotification: Serialize + DeserializeOwned {
+    /// Method name on the wire (e.g. "notifications/progress")
+    const METHOD: &'static str;
+
+    /// Build the JSON-RPC notification
+    ///
+    /// Params that serialize to an empty object are omitted.
+    fn into_notification(self) -> Result<JsonRpcNotification, McpError> {
+        let params = serde_json::to_value(self)
+            .map_err(|e| McpError::internal_error(format!("Invalid params: {}", e)))?;
+        match params {
+            Value::Object(map) if map.is_empty() => Ok(JsonRpcNotification::new(Self::METHOD)),
+            params => Ok(JsonRpcNotification::with_params(Self::METHOD, params)),
+        }
+    }
+
+    /// Decode these parameters from a JSON-RPC notification
+    ///
+    /// Missing params are decoded from an empty object.
+    fn from_notification(notification: &JsonRpcNotification) -> Result<Self, McpError> {
+        if notification.method != Self::METHOD {
+            return Err(McpError::invalid_request(format!(
+                "Expected method {}, got {}",
+                Self::METHOD,
+                notification.method
+            )));
+        }
+        let params = notification
+            .params
+            .clone()
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        serde_json::from_value(params).map_err(|e| McpError::invalid_params(e.to_string()))
+    }
+}
+
+impl McpRequest for InitializeParams {
+    const METHOD: &'static str = "initialize";
+    type Result = InitializeResult;
+}
+
+impl McpRequest for PingRequest {
+    const METHOD: &'static str = "ping";
+    type Result = EmptyResult;
+}
+
+impl McpNotification for InitializedNotification {
+    const METHOD: &'static str = "notifications/initialized";
+}
+
+impl Implementation {
+    /// Create implementation metadata
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
         Self {
-            roots: None,
-            sampling: None,
-            experimental: None,
+            name: name.into(),
+            title: None,
+            version: version.into(),
+            icons: Vec::new(),
         }
     }
+
+    /// Set the display title
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add an icon
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icons.push(icon);
+        self
+    }
+}
+
+impl BaseMetadata for Implementation {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
 }
 
 impl JsonRpcRequest {
@@ -233,22 +531,111 @@ impl JsonRpcResponse {
         Self {
             jsonrpc: crate::JSONRPC_VERSION.to_string(),
             id,
-            result: Some(result),
-            error: None,
+            outcome: ResponseOutcome::Result(result),
         }
     }
 
     /// Create an error response
-    pub fn error(id: RequestId, error: crate::errors::McpError) -> Self {
+    pub fn error(id: RequestId, error: McpError) -> Self {
         Self {
             jsonrpc: crate::JSONRPC_VERSION.to_string(),
             id,
-            result: None,
-            error: Some(error),
+            outcome: ResponseOutcome::Error(error),
+        }
+    }
+
+    /// Whether the response carries a result
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome, ResponseOutcome::Result(_))
+    }
+
+    /// Raw result, if the response succeeded
+    pub fn as_result(&self) -> Option<&Value> {
+        match &self.outcome {
+            ResponseOutcome::Result(result) => Some(result),
+            ResponseOutcome::Error(_) => None,
+        }
+    }
+
+    /// Error, if the response failed
+    pub fn as_error(&self) -> Option<&McpError> {
+        match &self.outcome {
+            ResponseOutcome::Result(_) => None,
+            ResponseOutcome::Error(error) => Some(error),
+        }
+    }
+
+    /// Decode the result into `T`, or return the response error
+    pub fn into_result<T: DeserializeOwned>(self) -> Result<T, McpError> {
+        self.outcome.into_result()
+    }
+
+    /// Create a response from a handler's typed result
+    ///
+    /// A result that fails to serialize becomes an internal error response.
+    pub fn from_result<T: Serialize>(id: RequestId, result: Result<T, McpError>) -> Self {
+        let outcome = result.and_then(|result| {
+            serde_json::to_value(result)
+                .map_err(|e| McpError::internal_error(format!("Invalid result: {}", e)))
+        });
+        Self {
+            jsonrpc: crate::JSONRPC_VERSION.to_string(),
+            id,
+            outcome: outcome.into(),
+        }
+    }
+}
+
+impl ResponseOutcome {
+    /// Decode the result into `T`, or return the error
+    ///
+    /// A result that does not match `T` is reported as an internal error.
+    pub fn into_result<T: DeserializeOwned>(self) -> Result<T, McpError> {
+        match self {
+            Self::Result(result) => serde_json::from_value(result)
+                .map_err(|e| McpError::internal_error(format!("Invalid result: {}", e))),
+            Self::Error(error) => Err(error),
         }
     }
 }
 
+impl From<Result<Value, McpError>> for ResponseOutcome {
+    fn from(result: Result<Value, McpError>) -> Self {
+        match result {
+            Ok(result) => Self::Result(result),
+            Err(error) => Self::Error(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawJsonRpcResponse::deserialize(deserializer)?;
+        let outcome = match (raw.result, raw.error) {
+            (Some(result), None) => ResponseOutcome::Result(result),
+            (None, Some(error)) => ResponseOutcome::Error(error),
+            (Some(_), Some(_)) => {
+                return Err(serde::de::Error::custom(
+                    "response has both \"result\" and \"error\"",
+                ))
+            }
+            (None, None) => {
+                return Err(serde::de::Error::custom(
+                    "response has neither \"result\" nor \"error\"",
+                ))
+            }
+        };
+        Ok(Self {
+            jsonrpc: raw.jsonrpc,
+            id: raw.id,
+            outcome,
+        })
+    }
+}
+
 impl JsonRpcNotification {
     /// Create a new notification
     pub fn new(method: impl Into<String>) -> Self {
@@ -269,9 +656,439 @@ impl JsonRpcNotification {
     }
 }
 
+impl JsonRpcError {
+    /// Create a new error response
+    pub fn new(id: RequestId, error: McpError) -> Self {
+        Self {
+            jsonrpc: crate::JSONRPC_VERSION.to_string(),
+            id,
+            error,
+        }
+    }
+}
+
+impl JsonRpcMessage {
+    /// Parse a message from its JSON text
+    ///
+    /// Malformed JSON yields a parse error, well-formed JSON that is not a
+    /// valid message yields an invalid request error.
+    pub fn parse(text: &str) -> Result<Self, McpError> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| McpError::parse_error(format!("Invalid JSON: {}", e)))?;
+        Self::try_from(value)
+    }
+
+    /// Request identifier, if the message carries one
+    pub fn id(&self) -> Option<&RequestId> {
+        match self {
+            Self::Request(request) => Some(&request.id),
+            Self::Notification(_) => None,
+            Self::Response(response) => Some(&response.id),
+            Self::Error(error) => Some(&error.id),
+        }
+    }
+
+    /// Convert a `Response` or `Error` message into a [`JsonRpcResponse`]
+    pub fn into_response(self) -> Option<JsonRpcResponse> {
+        match self {
+            Self::Response(response) => Some(response),
+            Self::Error(error) => Some(error.into()),
+            Self::Request(_) | Self::Notification(_) => None,
+        }
+    }
+
+    /// Method name, if the message is a request or notification
+    pub fn method(&self) -> Option<&str> {
+        match self {
+            Self::Request(request) => Some(&request.method),
+            Self::Notification(notification) => Some(&notification.method),
+            Self::Response(_) | Self::Error(_) => None,
+        }
+    }
+
+    /// Classify a JSON object by the members it carries
+    fn classify(object: &Map<String, Value>) -> Result<MessageKind, McpError> {
+        match object.get("jsonrpc") {
+            Some(Value::String(version)) if version == crate::JSONRPC_VERSION => {}
+            Some(other) => {
+                return Err(McpError::invalid_request(format!(
+                    "Unsupported JSON-RPC version: {}",
+                    other
+                )))
+            }
+            None => return Err(McpError::invalid_request("Missing \"jsonrpc\" member")),
+        }
+
+        let has_id = object.contains_key("id");
+        let has_method = object.contains_key("method");
+        let has_result = object.contains_key("result");
+        let has_error = object.contains_key("error");
+        let null_id = matches!(object.get("id"), Some(Value::Null));
+
+        match (has_method, has_result, has_error) {
+            (true, false, false) if null_id => {
+                Err(McpError::invalid_request("Request id must not be null"))
+            }
+            (true, false, false) if has_id => Ok(MessageKind::Request),
+            (true, false, false) => Ok(MessageKind::Notification),
+            (true, _, _) => Err(McpError::invalid_request(
+                "Message has \"method\" together with \"result\" or \"error\"",
+            )),
+            (false, true, true) => Err(McpError::invalid_request(
+                "Response has both \"result\" and \"error\"",
+            )),
+            (false, true, false) if null_id => Err(McpError::invalid_request(
+                "Only error responses may carry a null id",
+            )),
+            (false, true, false) if has_id => Ok(MessageKind::Response),
+            (false, false, true) if has_id => Ok(MessageKind::Error),
+            (false, true, false) | (false, false, true) => {
+                Err(McpError::invalid_request("Response is missing \"id\""))
+            }
+            (false, false, false) => Err(McpError::invalid_request(
+                "Message has none of \"method\", \"result\" or \"error\"",
+            )),
+        }
+    }
+}
+
+/// Shape of a JSON-RPC message, decided before deserializing its fields
+enum MessageKind {
+    Request,
+    Notification,
+    Response,
+    Error,
+}
+
+impl TryFrom<Value> for JsonRpcMessage {
+    type Error = McpError;
+
+    fn try_from(value: Value) -> Result<Self, McpError> {
+        let kind = match &value {
+            Value::Object(object) => Self::classify(object)?,
+            _ => {
+                return Err(McpError::invalid_request(
+                    "JSON-RPC message must be an object",
+                ))
+            }
+        };
+
+        let invalid = |e: serde_json::Error| McpError::invalid_request(e.to_string());
+        Ok(match kind {
+            MessageKind::Request => Self::Request(serde_json::from_value(value).map_err(invalid)?),
+            MessageKind::Notification => {
+                Self::Notification(serde_json::from_value(value).map_err(invalid)?)
+            }
+            MessageKind::Response => {
+                Self::Response(serde_json::from_value(value).map_err(invalid)?)
+            }
+            MessageKind::Error => Self::Error(serde_json::from_value(value).map_err(invalid)?),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Self::try_from(value).map_err(|e| serde::de::Error::custom(e.message))
+    }
+}
+
+impl From<JsonRpcRequest> for JsonRpcMessage {
+    fn from(request: JsonRpcRequest) -> Self {
+        Self::Request(request)
+    }
+}
+
+impl From<JsonRpcNotification> for JsonRpcMessage {
+    fn from(notification: JsonRpcNotification) -> Self {
+        Self::Notification(notification)
+    }
+}
+
+impl From<JsonRpcResponse> for JsonRpcMessage {
+    /// Error responses become [`JsonRpcMessage::Error`]
+    fn from(response: JsonRpcResponse) -> Self {
+        match response.outcome {
+            ResponseOutcome::Result(_) => Self::Response(response),
+            ResponseOutcome::Error(error) => Self::Error(JsonRpcError {
+                jsonrpc: response.jsonrpc,
+                id: response.id,
+                error,
+            }),
+        }
+    }
+}
+
+impl From<JsonRpcError> for JsonRpcResponse {
+    fn from(error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: error.jsonrpc,
+            id: error.id,
+            outcome: ResponseOutcome::Error(error.error),
+        }
+    }
+}
+
+impl From<JsonRpcError> for JsonRpcMessage {
+    fn from(error: JsonRpcError) -> Self {
+        Self::Error(error)
+    }
+}
+
+impl Side {
+    /// The other side of the connection
+    pub fn peer(&self) -> Side {
+        match self {
+            Self::Client => Self::Server,
+            Self::Server => Self::Client,
+        }
+    }
+}
+
+impl RequestId {
+    /// Whether this is the null identifier
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(id) => f.write_str(id),
+            Self::Number(id) => write!(f, "{}", id),
+            Self::Null => f.write_str("null"),
+        }
+    }
+}
+
+impl From<i64> for RequestId {
+    fn from(id: i64) -> Self {
+        Self::Number(id)
+    }
+}
+
+impl From<String> for RequestId {
+    fn from(id: String) -> Self {
+        Self::String(id)
+    }
+}
+
+impl From<&str> for RequestId {
+    fn from(id: &str) -> Self {
+        Self::String(id.to_string())
+    }
+}
+
+impl SequentialIdGenerator {
+    /// Create a generator whose first id is 1
+    pub fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// Create a generator whose first id is `start`
+    pub fn starting_at(start: i64) -> Self {
+        Self {
+            next: AtomicI64::new(start),
+        }
+    }
+}
+
+impl Default for SequentialIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestIdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> RequestId {
+        RequestId::Number(self.next.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl RequestIdGenerator for UuidIdGenerator {
+    fn next_id(&self) -> RequestId {
+        RequestId::String(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl JsonRpcRequestBatch {
+    /// Create a batch, validating that it is non-empty and holds only
+    /// requests and notifications with unique ids
+    pub fn new(messages: Vec<JsonRpcMessage>) -> Result<Self, McpError> {
+        if messages.is_empty() {
+            return Err(McpError::invalid_request("Batch must not be empty"));
+        }
+        let mut seen = HashSet::new();
+        for message in &messages {
+            match message {
+                JsonRpcMessage::Request(request) => {
+                    if !seen.insert(&request.id) {
+                        return Err(McpError::invalid_request(format!(
+                            "Duplicate request id in batch: {}",
+                            request.id
+                        )));
+                    }
+                }
+                JsonRpcMessage::Notification(_) => {}
+                JsonRpcMessage::Response(_) | JsonRpcMessage::Error(_) => {
+                    return Err(McpError::invalid_request(
+                        "Request batch must contain only requests and notifications",
+                    ))
+                }
+            }
+        }
+        Ok(Self(messages))
+    }
+
+    /// Messages in the batch, in order
+    pub fn messages(&self) -> &[JsonRpcMessage] {
+        &self.0
+    }
+
+    /// Consume the batch, returning its messages
+    pub fn into_messages(self) -> Vec<JsonRpcMessage> {
+        self.0
+    }
+
+    /// Requests in the batch (notifications are skipped)
+    pub fn requests(&self) -> impl Iterator<Item = &JsonRpcRequest> {
+        self.0.iter().filter_map(|message| match message {
+            JsonRpcMessage::Request(request) => Some(request),
+            _ => None,
+        })
+    }
+
+    /// Whether the batch expects any response at all
+    pub fn expects_response(&self) -> bool {
+        self.requests().next().is_some()
+    }
+}
+
+impl JsonRpcResponseBatch {
+    /// Create a batch, validating that it is non-empty and holds only responses
+    pub fn new(messages: Vec<JsonRpcMessage>) -> Result<Self, McpError> {
+        if messages.is_empty() {
+            return Err(McpError::invalid_request("Batch must not be empty"));
+        }
+        if messages.iter().any(|message| {
+            !matches!(
+                message,
+                JsonRpcMessage::Response(_) | JsonRpcMessage::Error(_)
+            )
+        }) {
+            return Err(McpError::invalid_request(
+                "Response batch must contain only responses",
+            ));
+        }
+        Ok(Self(messages))
+    }
+
+    /// Messages in the batch, in order
+    pub fn messages(&self) -> &[JsonRpcMessage] {
+        &self.0
+    }
+
+    /// Consume the batch, returning its messages
+    pub fn into_messages(self) -> Vec<JsonRpcMessage> {
+        self.0
+    }
+
+    /// Match each response to the request with the same id
+    ///
+    /// Responses may arrive in any order. A second response for an id that
+    /// was already answered is reported as unmatched.
+    pub fn pair_with<'a>(&'a self, requests: &'a JsonRpcRequestBatch) -> BatchPairing<'a> {
+        let mut pending: Vec<&JsonRpcRequest> = requests.requests().collect();
+        let mut pairs = Vec::new();
+        let mut unmatched = Vec::new();
+
+        for response in &self.0 {
+            let position = response
+                .id()
+                .and_then(|id| pending.iter().position(|request| &request.id == id));
+            match position {
+                Some(index) => pairs.push((pending.remove(index), response)),
+                None => unmatched.push(response),
+            }
+        }
+
+        BatchPairing {
+            pairs,
+            unanswered: pending,
+            unmatched,
+        }
+    }
+}
+
+impl JsonRpcPayload {
+    /// Parse a single message or a batch from its JSON text
+    pub fn parse(text: &str) -> Result<Self, McpError> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| McpError::parse_error(format!("Invalid JSON: {}", e)))?;
+        Self::try_from(value)
+    }
+}
+
+impl TryFrom<Value> for JsonRpcPayload {
+    type Error = McpError;
+
+    fn try_from(value: Value) -> Result<Self, McpError> {
+        let items = match value {
+            Value::Array(items) => items,
+            other => return JsonRpcMessage::try_from(other).map(Self::Message),
+        };
+        let messages = items
+            .into_iter()
+            .map(JsonRpcMessage::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        match messages.first() {
+            Some(JsonRpcMessage::Response(_) | JsonRpcMessage::Error(_)) => {
+                JsonRpcResponseBatch::new(messages).map(Self::ResponseBatch)
+            }
+            _ => JsonRpcRequestBatch::new(messages).map(Self::RequestBatch),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcRequestBatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let messages = Vec::<JsonRpcMessage>::deserialize(deserializer)?;
+        Self::new(messages).map_err(|e| serde::de::Error::custom(e.message))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcResponseBatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let messages = Vec::<JsonRpcMessage>::deserialize(deserializer)?;
+        Self::new(messages).map_err(|e| serde::de::Error::custom(e.message))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonRpcPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Self::try_from(value).map_err(|e| serde::de::Error::custom(e.message))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::ErrorCode;
     use serde_json::json;
 
     #[test]
@@ -302,4 +1119,266 @@ mod tests {
         let deserialized: ServerCapabilities = serde_json::from_str(&json).unwrap();
         assert_eq!(caps, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_message_classification() {
+        let request: JsonRpcMessage =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "id": 1, "method": "ping"})).unwrap();
+        assert!(matches!(request, JsonRpcMessage::Request(_)));
+
+        let notification: JsonRpcMessage = serde_json::from_value(
+            json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+        )
+        .unwrap();
+        assert!(matches!(notification, JsonRpcMessage::Notification(_)));
+
+        let response: JsonRpcMessage =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "id": "a", "result": {}})).unwrap();
+        assert!(matches!(response, JsonRpcMessage::Response(_)));
+
+        let error: JsonRpcMessage = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": {"code": -32700, "message": "Parse error"}
+        }))
+        .unwrap();
+        assert!(matches!(error, JsonRpcMessage::Error(_)));
+        assert_eq!(error.id(), Some(&RequestId::Null));
+
+        let not_found = JsonRpcMessage::parse(
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32002,"message":"Resource not found"}}"#,
+        )
+        .unwrap();
+        let response = not_found.into_response().unwrap();
+        assert!(matches!(
+            response.outcome,
+            ResponseOutcome::Error(McpError {
+                code: ErrorCode::Other(-32002),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_message_rejects_ambiguous_shapes() {
+        let cases = [
+            json!({"jsonrpc": "2.0", "id": 1, "method": "ping", "result": {}}),
+            json!({"jsonrpc": "2.0", "id": 1, "result": {}, "error": {"code": -32603, "message": "x"}}),
+            json!({"jsonrpc": "2.0", "result": {}}),
+            json!({"jsonrpc": "2.0", "id": 1}),
+            json!({"jsonrpc": "1.0", "id": 1, "method": "ping"}),
+            json!({"id": 1, "method": "ping"}),
+            json!([1, 2, 3]),
+        ];
+        for case in cases {
+            let error = JsonRpcMessage::try_from(case).unwrap_err();
+            assert_eq!(error.code, crate::errors::ErrorCode::InvalidRequest);
+        }
+
+        let error = JsonRpcMessage::parse("{not json").unwrap_err();
+        assert_eq!(error.code, crate::errors::ErrorCode::ParseError);
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let message = JsonRpcMessage::from(JsonRpcRequest::with_params(
+            RequestId::Number(7),
+            "tools/call",
+            json!({"name": "echo"}),
+        ));
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(JsonRpcMessage::parse(&json).unwrap(), message);
+        assert_eq!(message.method(), Some("tools/call"));
+    }
+
+    #[test]
+    fn test_batch_round_trip() {
+        let batch = JsonRpcRequestBatch::new(vec![
+            JsonRpcRequest::new(RequestId::Number(1), "tools/list").into(),
+            JsonRpcNotification::new("notifications/initialized").into(),
+            JsonRpcRequest::new(RequestId::Number(2), "prompts/list").into(),
+        ])
+        .unwrap();
+        let json = serde_json::to_string(&batch).unwrap();
+        let deserialized: JsonRpcRequestBatch = serde_json::from_str(&json).unwrap();
+        assert_eq!(batch, deserialized);
+        assert!(matches!(
+            JsonRpcPayload::parse(&json).unwrap(),
+            JsonRpcPayload::RequestBatch(_)
+        ));
+    }
+
+    #[test]
+    fn test_batch_validation() {
+        assert!(JsonRpcRequestBatch::new(vec![]).is_err());
+        assert!(serde_json::from_str::<JsonRpcResponseBatch>("[]").is_err());
+        assert!(JsonRpcRequestBatch::new(vec![
+            JsonRpcRequest::new(RequestId::Number(1), "ping").into(),
+            JsonRpcRequest::new(RequestId::Number(1), "ping").into(),
+        ])
+        .is_err());
+        assert!(JsonRpcResponseBatch::new(vec![JsonRpcNotification::new("ping").into()]).is_err());
+        assert!(JsonRpcPayload::parse(
+            r#"[{"jsonrpc": "2.0", "id": 1, "result": {}}, {"jsonrpc": "2.0", "method": "ping"}]"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_batch_pairing() {
+        let requests = JsonRpcRequestBatch::new(vec![
+            JsonRpcRequest::new(RequestId::Number(1), "tools/list").into(),
+            JsonRpcNotification::new("notifications/initialized").into(),
+            JsonRpcRequest::new(RequestId::String("b".to_string()), "prompts/list").into(),
+            JsonRpcRequest::new(RequestId::Number(3), "resources/list").into(),
+        ])
+        .unwrap();
+        let responses = JsonRpcResponseBatch::new(vec![
+            JsonRpcResponse::success(RequestId::String("b".to_string()), json!({})).into(),
+            JsonRpcResponse::success(RequestId::Number(1), json!({})).into(),
+            JsonRpcError::new(RequestId::Null, McpError::invalid_request("bad")).into(),
+        ])
+        .unwrap();
+
+        let pairing = responses.pair_with(&requests);
+        assert_eq!(pairing.pairs.len(), 2);
+        assert_eq!(pairing.pairs[0].0.method, "prompts/list");
+        assert_eq!(pairing.pairs[1].0.method, "tools/list");
+        assert_eq!(pairing.unanswered.len(), 1);
+        assert_eq!(pairing.unanswered[0].id, RequestId::Number(3));
+        assert_eq!(pairing.unmatched.len(), 1);
+    }
+
+    #[test]
+    fn test_response_outcome_exclusive() {
+        let both = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {},
+            "error": {"code": -32603, "message": "x"}
+        });
+        assert!(serde_json::from_value::<JsonRpcResponse>(both).is_err());
+
+        let neither = json!({"jsonrpc": "2.0", "id": 1});
+        assert!(serde_json::from_value::<JsonRpcResponse>(neither).is_err());
+
+        let null_result = json!({"jsonrpc": "2.0", "id": 1, "result": null});
+        let response: JsonRpcResponse = serde_json::from_value(null_result).unwrap();
+        assert_eq!(response.outcome, ResponseOutcome::Result(Value::Null));
+    }
+
+    #[test]
+    fn test_response_into_result() {
+        let response = JsonRpcResponse::success(RequestId::Number(1), json!({}));
+        let result: EmptyResult = response.into_result().unwrap();
+        assert_eq!(result, EmptyResult::default());
+
+        let error = McpError::method_not_found("unknown");
+        let response = JsonRpcResponse::error(RequestId::Number(2), error.clone());
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("result").is_none());
+        let deserialized: JsonRpcResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.into_result::<EmptyResult>(), Err(error));
+
+        let mismatched = JsonRpcResponse::success(RequestId::Number(3), json!(42));
+        let error = mismatched.into_result::<InitializeResult>().unwrap_err();
+        assert_eq!(error.code, crate::errors::ErrorCode::InternalError);
+    }
+
+    #[test]
+    fn test_typed_request_round_trip() {
+        let request = PingRequest::default()
+            .into_request(RequestId::Number(1))
+            .unwrap();
+        assert_eq!(request.method, "ping");
+        assert_eq!(
+            PingRequest::from_request(&request).unwrap(),
+            PingRequest::default()
+        );
+
+        let bare = JsonRpcRequest::new(RequestId::Number(2), "ping");
+        assert!(PingRequest::from_request(&bare).is_ok());
+
+        let wrong = JsonRpcRequest::new(RequestId::Number(3), "initialize");
+        assert!(PingRequest::from_request(&wrong).is_err());
+
+        let response =
+            JsonRpcResponse::from_result(RequestId::Number(1), Ok(EmptyResult::default()));
+        assert_eq!(
+            PingRequest::decode_result(response).unwrap(),
+            EmptyResult::default()
+        );
+    }
+
+    #[test]
+    fn test_typed_request_invalid_params() {
+        let request = JsonRpcRequest::with_params(
+            RequestId::Number(1),
+            InitializeParams::METHOD,
+            json!({"protocolVersion": 1}),
+        );
+        let error = InitializeParams::from_request(&request).unwrap_err();
+        assert_eq!(error.code, crate::errors::ErrorCode::InvalidParams);
+    }
+
+    #[test]
+    fn test_request_id_conversions() {
+        assert_eq!(RequestId::from(5), RequestId::Number(5));
+        assert_eq!(RequestId::from("abc"), RequestId::String("abc".to_string()));
+        assert_eq!(RequestId::from(5).to_string(), "5");
+        assert_eq!(RequestId::from("abc").to_string(), "abc");
+        assert_eq!(RequestId::Null.to_string(), "null");
+        assert!(RequestId::Null.is_null());
+
+        let mut ids = vec![
+            RequestId::Null,
+            RequestId::from(2),
+            RequestId::from("a"),
+            RequestId::from(1),
+        ];
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                RequestId::from("a"),
+                RequestId::from(1),
+                RequestId::from(2),
+                RequestId::Null
+            ]
+        );
+    }
+
+    #[test]
+    fn test_request_id_generators() {
+        let generator = SequentialIdGenerator::new();
+        assert_eq!(generator.next_id(), RequestId::Number(1));
+        assert_eq!(generator.next_id(), RequestId::Number(2));
+        assert_eq!(
+            SequentialIdGenerator::starting_at(100).next_id(),
+            RequestId::Number(100)
+        );
+
+        let generator = UuidIdGenerator;
+        let ids: HashSet<RequestId> = (0..16).map(|_| generator.next_id()).collect();
+        assert_eq!(ids.len(), 16);
+        assert!(ids
+            .iter()
+            .all(|id| matches!(id, RequestId::String(s) if s.len() == 36)));
+    }
+
+    #[test]
+    fn test_null_id_handling() {
+        let request = json!({"jsonrpc": "2.0", "id": null, "method": "ping"});
+        assert!(JsonRpcMessage::try_from(request).is_err());
+
+        let success = json!({"jsonrpc": "2.0", "id": null, "result": {}});
+        assert!(JsonRpcMessage::try_from(success).is_err());
+
+        let error = json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": {"code": -32700, "message": "Parse error"}
+        });
+        assert!(JsonRpcMessage::try_from(error).is_ok());
+    }
+}
@@ -1,7 +1,11 @@
 //! Resource access and management types.
 
+use crate::annotations::{self, Annotations, Role};
+use crate::content::ContentBlock;
+use crate::meta::Meta;
+use crate::metadata::{BaseMetadata, Icon};
+use crate::protocol::{EmptyResult, McpNotification, McpRequest};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 /// Resource definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -11,6 +15,9 @@ pub struct Resource {
     /// Human-readable name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Resource description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -18,6 +25,15 @@ pub struct Resource {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "mimeType")]
     pub mime_type: Option<String>,
+    /// Size of the raw content in bytes, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// Hints about audience and priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Annotations>,
+    /// Icons a client may show
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<Icon>,
 }
 
 /// Resource template with URI pattern
@@ -29,6 +45,9 @@ pub struct ResourceTemplate {
     /// Human-readable name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Template description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -36,14 +55,24 @@ pub struct ResourceTemplate {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "mimeType")]
     pub mime_type: Option<String>,
+    /// Hints about audience and priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Annotations>,
+    /// Icons a client may show
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<Icon>,
 }
 
 /// Request to list available resources
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListResourcesRequest {
     /// Optional cursor for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Response with list of available resources
@@ -55,6 +84,10 @@ pub struct ListResourcesResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "nextCursor")]
     pub next_cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Request to read a specific resource
@@ -62,6 +95,10 @@ pub struct ListResourcesResult {
 pub struct ReadResourceRequest {
     /// Resource URI to read
     pub uri: String,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Resource content data
@@ -69,14 +106,70 @@ pub struct ReadResourceRequest {
 pub struct ReadResourceResult {
     /// Resource contents
     pub contents: Vec<ResourceContents>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Request to list available resource templates
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListResourceTemplatesRequest {
+    /// Optional cursor for pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Response with list of available resource templates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListResourceTemplatesResult {
+    /// Available resource templates
+    #[serde(rename = "resourceTemplates")]
+    pub resource_templates: Vec<ResourceTemplate>,
+    /// Pagination cursor for next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Request to be notified when a resource changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscribeRequest {
+    /// Resource URI to watch
+    pub uri: String,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Request to stop being notified when a resource changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsubscribeRequest {
+    /// Resource URI to stop watching
+    pub uri: String,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Content of a resource
+///
+/// Text and blob contents are told apart by which of `text` or `blob` is
+/// present; there is no `type` field on the wire.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "type")]
+#[serde(untagged)]
 pub enum ResourceContents {
     /// Text content
-    #[serde(rename = "text")]
     Text {
         /// Text content
         text: String,
@@ -88,7 +181,6 @@ pub enum ResourceContents {
         mime_type: Option<String>,
     },
     /// Binary content
-    #[serde(rename = "blob")]
     Blob {
         /// Base64-encoded binary data
         blob: String,
@@ -101,6 +193,84 @@ pub enum ResourceContents {
     },
 }
 
+/// Reference to a resource, returned in content without embedding it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceLink {
+    /// Resource URI
+    pub uri: String,
+    /// Human-readable name
+    pub name: String,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Resource description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// MIME type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+    /// Size of the raw content in bytes, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// Hints about audience and priority
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Annotations>,
+}
+
+/// Notification that the server's resource list has changed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceListChangedNotification {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Notification that a subscribed resource has changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUpdatedNotification {
+    /// URI of the updated resource
+    pub uri: String,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpRequest for ListResourcesRequest {
+    const METHOD: &'static str = "resources/list";
+    type Result = ListResourcesResult;
+}
+
+impl McpRequest for ReadResourceRequest {
+    const METHOD: &'static str = "resources/read";
+    type Result = ReadResourceResult;
+}
+
+impl McpRequest for ListResourceTemplatesRequest {
+    const METHOD: &'static str = "resources/templates/list";
+    type Result = ListResourceTemplatesResult;
+}
+
+impl McpRequest for SubscribeRequest {
+    const METHOD: &'static str = "resources/subscribe";
+    type Result = EmptyResult;
+}
+
+impl McpRequest for UnsubscribeRequest {
+    const METHOD: &'static str = "resources/unsubscribe";
+    type Result = EmptyResult;
+}
+
+impl McpNotification for ResourceListChangedNotification {
+    const METHOD: &'static str = "notifications/resources/list_changed";
+}
+
+impl McpNotification for ResourceUpdatedNotification {
+    const METHOD: &'static str = "notifications/resources/updated";
+}
+
 impl ResourceContents {
     /// Create text content
     pub fn text(uri: impl Into<String>, text: impl Into<String>) -> Self {
@@ -112,11 +282,197 @@ impl ResourceContents {
     }
 
     /// Create blob content
-    pub fn blob(uri: impl Into<String>, blob: impl Into<String>, mime_type: impl Into<String>) -> Self {
+    pub fn blob(
+        uri: impl Into<String>,
+        blob: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Self {
         Self::Blob {
             blob: blob.into(),
             uri: uri.into(),
             mime_type: Some(mime_type.into()),
         }
     }
-}
\ No newline at end of file
+
+    /// URI of the resource
+    pub fn uri(&self) -> &str {
+        match self {
+            Self::Text { uri, .. } | Self::Blob { uri, .. } => uri,
+        }
+    }
+
+    /// MIME type, if known
+    pub fn mime_type(&self) -> Option<&str> {
+        match self {
+            Self::Text { mime_type, .. } | Self::Blob { mime_type, .. } => mime_type.as_deref(),
+        }
+    }
+}
+
+impl BaseMetadata for Resource {
+    /// The resource's name, or its URI if it has none
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.uri)
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+impl BaseMetadata for ResourceTemplate {
+    /// The template's name, or its URI template if it has none
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.uri_template)
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+impl BaseMetadata for ResourceLink {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+}
+
+impl Resource {
+    /// Whether this resource is meant for `role`, per its audience annotation
+    pub fn is_for(&self, role: Role) -> bool {
+        annotations::is_for(self.annotations.as_ref(), role)
+    }
+}
+
+impl ListResourcesResult {
+    /// Resources ordered from highest to lowest priority
+    ///
+    /// Resources without a priority come last; ties keep their listed order.
+    pub fn by_priority(&self) -> Vec<&Resource> {
+        let priority = |resource: &Resource| {
+            resource
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.priority)
+        };
+        let mut resources: Vec<&Resource> = self.resources.iter().collect();
+        resources.sort_by(|a, b| {
+            priority(b)
+                .partial_cmp(&priority(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        resources
+    }
+}
+
+impl ReadResourceResult {
+    /// Embed every returned content as a `resource` content block
+    pub fn into_content(self) -> Vec<ContentBlock> {
+        self.contents.into_iter().map(ContentBlock::from).collect()
+    }
+}
+
+impl ResourceLink {
+    /// Create a link to `uri`
+    pub fn new(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            name: name.into(),
+            title: None,
+            description: None,
+            mime_type: None,
+            size: None,
+            annotations: None,
+        }
+    }
+
+    /// Set the description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the MIME type
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Set the size in bytes
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl From<&Resource> for ResourceLink {
+    /// Link to a listed resource, named after its URI if it has no name
+    fn from(resource: &Resource) -> Self {
+        Self {
+            uri: resource.uri.clone(),
+            name: resource
+                .name
+                .clone()
+                .unwrap_or_else(|| resource.uri.clone()),
+            title: resource.title.clone(),
+            description: resource.description.clone(),
+            mime_type: resource.mime_type.clone(),
+            size: resource.size,
+            annotations: resource.annotations.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(uri: &str, priority: Option<f64>) -> Resource {
+        Resource {
+            uri: uri.to_string(),
+            name: None,
+            title: None,
+            description: None,
+            mime_type: None,
+            size: None,
+            annotations: priority.map(|p| Annotations::new().with_priority(p).unwrap()),
+            icons: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_resources_by_priority() {
+        let result = ListResourcesResult {
+            resources: vec![
+                resource("file:///none", None),
+                resource("file:///low", Some(0.2)),
+                resource("file:///high", Some(0.9)),
+            ],
+            next_cursor: None,
+            meta: None,
+        };
+        let uris: Vec<&str> = result
+            .by_priority()
+            .into_iter()
+            .map(|resource| resource.uri.as_str())
+            .collect();
+        assert_eq!(uris, ["file:///high", "file:///low", "file:///none"]);
+        assert!(result.resources[0].is_for(Role::User));
+    }
+
+    #[test]
+    fn test_resource_list_changed_round_trip() {
+        let notification = ResourceListChangedNotification::default();
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.method, "notifications/resources/list_changed");
+        assert_eq!(json_rpc.params, None);
+        assert_eq!(
+            ResourceListChangedNotification::from_notification(&json_rpc).unwrap(),
+            notification
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Filesystem roots exposed by the client.
+//!
+//! Servers ask the client which directories they may operate on with
+//! `roots/list`. Roots are `file://` URIs; [`Root::from_path`] and
+//! [`Root::to_path`] convert them to and from local paths, and
+//! [`Root::contains`] checks whether a resource URI lies inside a root.
+
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::protocol::{McpNotification, McpRequest};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// Directory the client allows the server to operate on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Root {
+    /// `file://` URI of the root
+    pub uri: String,
+    /// Human-readable name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Request for the client's list of roots
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListRootsRequest {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Response to a roots/list request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListRootsResult {
+    /// Roots exposed by the client
+    pub roots: Vec<Root>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+/// Notification that the client's list of roots has changed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RootsListChangedNotification {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl McpRequest for ListRootsRequest {
+    const METHOD: &'static str = "roots/list";
+    type Result = ListRootsResult;
+}
+
+impl McpNotification for RootsListChangedNotification {
+    const METHOD: &'static str = "notifications/roots/list_changed";
+}
+
+impl Root {
+    /// Create a root from a `file://` URI
+    ///
+    /// Fails if `uri` is not a valid `file://` URI.
+    pub fn new(uri: impl Into<String>) -> Result<Self, McpError> {
+        let uri = uri.into();
+        parse_file_uri(&uri)?;
+        Ok(Self {
+            uri,
+            name: None,
+            meta: None,
+        })
+    }
+
+    /// Create a root for a local directory
+    ///
+    /// Fails if `path` is not absolute.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, McpError> {
+        let path = path.as_ref();
+        let url = Url::from_directory_path(path).map_err(|_| {
+            McpError::invalid_params(format!("Root path must be absolute: {}", path.display()))
+        })?;
+        Ok(Self {
+            uri: url.into(),
+            name: None,
+            meta: None,
+        })
+    }
+
+    /// Set the human-readable name
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Local path of the root
+    ///
+    /// Fails if the URI is not a `file://` URI naming a local path.
+    pub fn to_path(&self) -> Result<PathBuf, McpError> {
+        parse_file_uri(&self.uri)?
+            .to_file_path()
+            .map_err(|_| McpError::invalid_params(format!("Not a local path: {}", self.uri)))
+    }
+
+    /// Whether `uri` names the root itself or something inside it
+    ///
+    /// URIs are compared segment by segment after normalization, so
+    /// `file:///project` contains `file:///project/src/main.rs` but not
+    /// `file:///project2` or `file:///project/../etc/passwd`. Unparseable
+    /// URIs are never contained.
+    pub fn contains(&self, uri: &str) -> bool {
+        let (Ok(root), Ok(uri)) = (parse_file_uri(&self.uri), Url::parse(uri)) else {
+            return false;
+        };
+        if root.scheme() != uri.scheme() || root.host() != uri.host() {
+            return false;
+        }
+        let root_segments = segments(&root);
+        let uri_segments = segments(&uri);
+        uri_segments.len() >= root_segments.len()
+            && root_segments.iter().zip(&uri_segments).all(|(a, b)| a == b)
+    }
+}
+
+impl ListRootsResult {
+    /// Create a result listing `roots`
+    pub fn new(roots: Vec<Root>) -> Self {
+        Self { roots, meta: None }
+    }
+
+    /// Whether `uri` lies inside any of the roots
+    pub fn contains(&self, uri: &str) -> bool {
+        self.roots.iter().any(|root| root.contains(uri))
+    }
+}
+
+/// Parse `uri`, requiring the `file` scheme
+fn parse_file_uri(uri: &str) -> Result<Url, McpError> {
+    let url = Url::parse(uri)
+        .map_err(|e| McpError::invalid_params(format!("Invalid root URI {}: {}", uri, e)))?;
+    if url.scheme() != "file" {
+        return Err(McpError::invalid_params(format!(
+            "Root URI must use the file scheme: {}",
+            uri
+        )));
+    }
+    Ok(url)
+}
+
+/// Non-empty path segments of `url`
+fn segments(url: &Url) -> Vec<&str> {
+    url.path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_list_roots_wire_format() {
+        let result = ListRootsResult::new(vec![Root::new("file:///home/user/project")
+            .unwrap()
+            .with_name("Project")]);
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            json!({"roots": [{"uri": "file:///home/user/project", "name": "Project"}]})
+        );
+        assert!(Root::new("https://example.com").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_conversion() {
+        let root = Root::from_path("/home/user/my project").unwrap();
+        assert_eq!(root.uri, "file:///home/user/my%20project/");
+        assert_eq!(
+            root.to_path().unwrap(),
+            PathBuf::from("/home/user/my project")
+        );
+        assert!(Root::from_path("relative/dir").is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let root = Root::new("file:///home/user/project").unwrap();
+        assert!(root.contains("file:///home/user/project"));
+        assert!(root.contains("file:///home/user/project/src/main.rs"));
+        assert!(!root.contains("file:///home/user/project2/main.rs"));
+        assert!(!root.contains("file:///home/user/project/../secrets"));
+        assert!(!root.contains("https://home/user/project/main.rs"));
+        assert!(!root.contains("not a uri"));
+
+        let result = ListRootsResult::new(vec![root, Root::new("file:///tmp/scratch/").unwrap()]);
+        assert!(result.contains("file:///tmp/scratch/notes.txt"));
+        assert!(!result.contains("file:///tmp/other"));
+    }
+
+    #[test]
+    fn test_roots_list_changed_round_trip() {
+        let notification = RootsListChangedNotification::default();
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.method, "notifications/roots/list_changed");
+        assert_eq!(json_rpc.params, None);
+        assert_eq!(
+            RootsListChangedNotification::from_notification(&json_rpc).unwrap(),
+            notification
+        );
+    }
+}
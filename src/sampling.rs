@@ -1,5 +1,13 @@
 //! LLM sampling integration.
 
+use crate::content::{
+    deserialize_sampling_content, no_legacy_equivalent, serialize_sampling_content, ContentBlock,
+    ContentContext,
+};
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::protocol::McpRequest;
+use crate::version::ProtocolVersion;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -8,8 +16,19 @@ use serde_json::Value;
 pub struct SamplingMessage {
     /// Message role
     pub role: MessageRole,
-    /// Message content
-    pub content: MessageContent,
+    /// Message content; resources are rejected when parsing or serializing
+    #[serde(serialize_with = "serialize_sampling_content")]
+    #[serde(deserialize_with = "deserialize_sampling_content")]
+    pub content: ContentBlock,
+}
+
+impl SamplingMessage {
+    /// Create a message, failing if `content` is not allowed in sampling
+    pub fn new(role: MessageRole, content: impl Into<ContentBlock>) -> Result<Self, McpError> {
+        let content = content.into();
+        content.check_allowed_in(ContentContext::Sampling)?;
+        Ok(Self { role, content })
+    }
 }
 
 /// Role of a message
@@ -24,7 +43,11 @@ pub enum MessageRole {
     System,
 }
 
-/// Content of a message
+/// Sampling message content as carried before [`ContentBlock`]
+///
+/// Converts into a `ContentBlock`; the conversion back fails for variants
+/// this type cannot hold.
+#[deprecated(note = "use `ContentBlock`, which `SamplingMessage` carries")]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum MessageContent {
@@ -45,6 +68,47 @@ pub enum MessageContent {
     },
 }
 
+#[allow(deprecated)]
+impl MessageContent {
+    /// Create text content
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Create image content
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<MessageContent> for ContentBlock {
+    fn from(content: MessageContent) -> Self {
+        match content {
+            MessageContent::Text { text } => Self::text(text),
+            MessageContent::Image { data, mime_type } => Self::image(data, mime_type),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<ContentBlock> for MessageContent {
+    type Error = McpError;
+
+    fn try_from(content: ContentBlock) -> Result<Self, McpError> {
+        match content {
+            ContentBlock::Text { text, .. } => Ok(Self::Text { text }),
+            ContentBlock::Image {
+                data, mime_type, ..
+            } => Ok(Self::Image { data, mime_type }),
+            content => Err(no_legacy_equivalent(&content, "MessageContent")),
+        }
+    }
+}
+
 /// Request to create a message
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateMessageRequest {
@@ -75,6 +139,10 @@ pub struct CreateMessageRequest {
     /// Additional metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Value>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Model preferences for sampling
@@ -116,19 +184,32 @@ pub struct CreateMessageResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "stopReason")]
     pub stop_reason: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-impl MessageContent {
-    /// Create text content
-    pub fn text(text: impl Into<String>) -> Self {
-        Self::Text { text: text.into() }
+impl McpRequest for CreateMessageRequest {
+    const METHOD: &'static str = "sampling/createMessage";
+    type Result = CreateMessageResult;
+}
+
+impl CreateMessageRequest {
+    /// Check that every message is allowed under `version`
+    ///
+    /// Sampling content is never downgraded, since a placeholder would
+    /// silently change what the model is asked.
+    pub fn check_version(&self, version: ProtocolVersion) -> Result<(), McpError> {
+        self.messages
+            .iter()
+            .try_for_each(|message| message.content.check_supported_by(version))
     }
+}
 
-    /// Create image content
-    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
-        Self::Image {
-            data: data.into(),
-            mime_type: mime_type.into(),
-        }
+impl CreateMessageResult {
+    /// Check that the generated message is allowed under `version`
+    pub fn check_version(&self, version: ProtocolVersion) -> Result<(), McpError> {
+        self.message.content.check_supported_by(version)
     }
-}
\ No newline at end of file
+}
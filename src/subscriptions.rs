@@ -0,0 +1,148 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! Resource subscription bookkeeping.
+//!
+//! [`SubscriptionSet`] records which subscribers asked for
+//! `resources/subscribe` on which URIs, and answers who must receive a
+//! `notifications/resources/updated` when a resource changes. It holds no
+//! connections itself: a subscriber is whatever key the server uses to
+//! address a client session.
+
+use crate::resources::ResourceUpdatedNotification;
+use std::collections::HashMap;
+
+/// Subscribers to resource updates, keyed by resource URI
+///
+/// URIs are matched exactly. Subscribers are reported in the order they
+/// subscribed.
+#[derive(Debug, Clone)]
+pub struct SubscriptionSet<S> {
+    by_uri: HashMap<String, Vec<S>>,
+}
+
+impl<S> Default for SubscriptionSet<S> {
+    fn default() -> Self {
+        Self {
+            by_uri: HashMap::new(),
+        }
+    }
+}
+
+impl<S: PartialEq + Clone> SubscriptionSet<S> {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe `subscriber` to `uri`
+    ///
+    /// Returns `false` if it was already subscribed.
+    pub fn subscribe(&mut self, subscriber: S, uri: impl Into<String>) -> bool {
+        let subscribers = self.by_uri.entry(uri.into()).or_default();
+        if subscribers.contains(&subscriber) {
+            return false;
+        }
+        subscribers.push(subscriber);
+        true
+    }
+
+    /// Unsubscribe `subscriber` from `uri`
+    ///
+    /// Returns `false` if it was not subscribed.
+    pub fn unsubscribe(&mut self, subscriber: &S, uri: &str) -> bool {
+        let Some(subscribers) = self.by_uri.get_mut(uri) else {
+            return false;
+        };
+        let before = subscribers.len();
+        subscribers.retain(|s| s != subscriber);
+        let removed = subscribers.len() != before;
+        if subscribers.is_empty() {
+            self.by_uri.remove(uri);
+        }
+        removed
+    }
+
+    /// Drop every subscription of `subscriber`, e.g. when its session ends
+    ///
+    /// Returns the number of subscriptions removed.
+    pub fn unsubscribe_all(&mut self, subscriber: &S) -> usize {
+        let mut removed = 0;
+        self.by_uri.retain(|_, subscribers| {
+            let before = subscribers.len();
+            subscribers.retain(|s| s != subscriber);
+            removed += before - subscribers.len();
+            !subscribers.is_empty()
+        });
+        removed
+    }
+
+    /// Whether `subscriber` is subscribed to `uri`
+    pub fn is_subscribed(&self, subscriber: &S, uri: &str) -> bool {
+        self.subscribers(uri).contains(subscriber)
+    }
+
+    /// Subscribers of `uri`
+    pub fn subscribers(&self, uri: &str) -> &[S] {
+        self.by_uri.get(uri).map_or(&[], Vec::as_slice)
+    }
+
+    /// Notifications to send now that `uri` has changed, one per subscriber
+    pub fn updated(&self, uri: &str) -> Vec<(S, ResourceUpdatedNotification)> {
+        self.subscribers(uri)
+            .iter()
+            .map(|subscriber| {
+                let notification = ResourceUpdatedNotification {
+                    uri: uri.to_string(),
+                    meta: None,
+                };
+                (subscriber.clone(), notification)
+            })
+            .collect()
+    }
+
+    /// Whether any resource is subscribed to
+    pub fn is_empty(&self) -> bool {
+        self.by_uri.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN: &str = "file:///project/src/main.rs";
+
+    #[test]
+    fn test_subscribe_and_notify() {
+        let mut set = SubscriptionSet::new();
+        assert!(set.subscribe("a", MAIN));
+        assert!(set.subscribe("b", MAIN));
+        assert!(!set.subscribe("a", MAIN));
+        assert!(set.subscribe("a", "file:///project/README.md"));
+
+        let updates = set.updated(MAIN);
+        assert_eq!(
+            updates.iter().map(|(s, _)| *s).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(updates[0].1.uri, MAIN);
+        assert!(set.updated("file:///project").is_empty());
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let mut set = SubscriptionSet::new();
+        set.subscribe(1, MAIN);
+        set.subscribe(2, MAIN);
+        set.subscribe(1, "file:///other");
+
+        assert!(set.unsubscribe(&2, MAIN));
+        assert!(!set.unsubscribe(&2, MAIN));
+        assert_eq!(set.subscribers(MAIN), [1]);
+
+        assert_eq!(set.unsubscribe_all(&1), 2);
+        assert!(!set.is_subscribed(&1, MAIN));
+        assert!(set.is_empty());
+    }
+}
@@ -1,5 +1,15 @@
 //! Tool definitions and execution types.
 
+use crate::annotations::Role;
+use crate::content::{legacy_resource, no_legacy_equivalent, ContentBlock};
+#[cfg(feature = "validation")]
+use crate::errors::ErrorCode;
+use crate::errors::McpError;
+use crate::meta::Meta;
+use crate::metadata::{BaseMetadata, Icon};
+use crate::protocol::{McpNotification, McpRequest};
+use crate::version::ProtocolVersion;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -8,12 +18,67 @@ use serde_json::Value;
 pub struct Tool {
     /// Tool name (must be unique within a server)
     pub name: String,
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Human-readable description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// JSON Schema for tool input parameters
     #[serde(rename = "inputSchema")]
     pub input_schema: ToolInputSchema,
+    /// JSON Schema (of type "object") for the tool's structured content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "outputSchema")]
+    pub output_schema: Option<Value>,
+    /// Hints about the tool's behavior
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+    /// Icons a client may show
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<Icon>,
+}
+
+/// Hints describing a tool's behavior
+///
+/// Hints are untrusted unless the server is: clients must not rely on them
+/// to make security decisions about tools from untrusted servers. Absent
+/// hints take the defaults given by the accessor methods.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolAnnotations {
+    /// Human-readable title for display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether the tool leaves its environment unmodified (default `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "readOnlyHint")]
+    pub read_only_hint: Option<bool>,
+    /// Whether modifications may be destructive (default `true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "destructiveHint")]
+    pub destructive_hint: Option<bool>,
+    /// Whether repeated calls with the same arguments have no further effect
+    /// (default `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "idempotentHint")]
+    pub idempotent_hint: Option<bool>,
+    /// Whether the tool interacts with external entities (default `true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "openWorldHint")]
+    pub open_world_hint: Option<bool>,
+}
+
+/// Client policy for asking the user before calling a tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationPolicy {
+    /// Confirm every call; the safe choice for untrusted servers
+    Always,
+    /// Confirm calls to tools that may modify their environment
+    UnlessReadOnly,
+    /// Confirm calls to tools that may perform destructive updates
+    Destructive,
+    /// Never confirm
+    Never,
 }
 
 /// JSON Schema for tool input parameters
@@ -29,16 +94,29 @@ pub struct ToolInputSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<Vec<String>>,
     /// Additional schema properties
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_additional")]
     pub additional: Option<Value>,
 }
 
+/// Treat an empty set of flattened schema properties as absent
+fn deserialize_additional<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let additional = Option::<Value>::deserialize(deserializer)?;
+    Ok(additional.filter(|value| !matches!(value, Value::Object(map) if map.is_empty())))
+}
+
 /// Request to list available tools
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListToolsRequest {
     /// Optional cursor for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Response with list of available tools
@@ -50,6 +128,10 @@ pub struct ListToolsResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "nextCursor")]
     pub next_cursor: Option<String>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Request to call a specific tool
@@ -60,20 +142,36 @@ pub struct CallToolRequest {
     /// Tool arguments matching the input schema
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arguments: Option<Value>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
 /// Tool execution result
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CallToolResult {
     /// Result content
-    pub content: Vec<ToolResultContent>,
+    pub content: Vec<ContentBlock>,
+    /// Machine-readable result, matching the tool's output schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "structuredContent")]
+    pub structured_content: Option<Value>,
     /// Whether the tool call failed
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "isError")]
     pub is_error: Option<bool>,
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
 }
 
-/// Content returned from tool execution
+/// Tool result content as carried before [`ContentBlock`]
+///
+/// Converts into a `ContentBlock`; the conversion back fails for variants
+/// this type cannot hold.
+#[deprecated(note = "use `ContentBlock`, which `CallToolResult` carries")]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum ToolResultContent {
@@ -100,11 +198,86 @@ pub enum ToolResultContent {
     },
 }
 
+#[allow(deprecated)]
+impl ToolResultContent {
+    /// Create text content
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Create image content
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+
+    /// Create resource reference
+    pub fn resource(uri: impl Into<String>) -> Self {
+        Self::Resource {
+            resource: uri.into(),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<ToolResultContent> for ContentBlock {
+    fn from(content: ToolResultContent) -> Self {
+        match content {
+            ToolResultContent::Text { text } => Self::text(text),
+            ToolResultContent::Image { data, mime_type } => Self::image(data, mime_type),
+            ToolResultContent::Resource { resource } => legacy_resource(resource),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<ContentBlock> for ToolResultContent {
+    type Error = McpError;
+
+    fn try_from(content: ContentBlock) -> Result<Self, McpError> {
+        match content {
+            ContentBlock::Text { text, .. } => Ok(Self::Text { text }),
+            ContentBlock::Image {
+                data, mime_type, ..
+            } => Ok(Self::Image { data, mime_type }),
+            ContentBlock::ResourceLink(link) => Ok(Self::Resource { resource: link.uri }),
+            content => Err(no_legacy_equivalent(&content, "ToolResultContent")),
+        }
+    }
+}
+
+/// Notification that the server's tool list has changed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolListChangedNotification {
+    /// Protocol-level metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "_meta")]
+    pub meta: Option<Meta>,
+}
+
+impl BaseMetadata for Tool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The tool's title, falling back to the title in its annotations
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref().or_else(|| {
+            self.annotations
+                .as_ref()
+                .and_then(|annotations| annotations.title.as_deref())
+        })
+    }
+}
+
 impl Tool {
     /// Create a new tool with basic schema
     pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
         Self {
             name: name.into(),
+            title: None,
             description: Some(description.into()),
             input_schema: ToolInputSchema {
                 type_: "object".to_string(),
@@ -112,9 +285,85 @@ impl Tool {
                 required: None,
                 additional: None,
             },
+            output_schema: None,
+            annotations: None,
+            icons: Vec::new(),
         }
     }
 
+    /// Set the display title
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Declare the JSON Schema of the tool's structured content
+    pub fn with_output_schema(mut self, schema: Value) -> Self {
+        self.output_schema = Some(schema);
+        self
+    }
+
+    /// Set the behavior hints
+    pub fn with_annotations(mut self, annotations: ToolAnnotations) -> Self {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    /// Add an icon
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icons.push(icon);
+        self
+    }
+
+    /// Behavior hints, with every hint absent if none were given
+    pub fn hints(&self) -> ToolAnnotations {
+        self.annotations.clone().unwrap_or_default()
+    }
+
+    /// Whether a call to this tool should be confirmed by the user under `policy`
+    pub fn requires_confirmation(&self, policy: ConfirmationPolicy) -> bool {
+        let hints = self.hints();
+        match policy {
+            ConfirmationPolicy::Always => true,
+            ConfirmationPolicy::UnlessReadOnly => !hints.is_read_only(),
+            ConfirmationPolicy::Destructive => hints.is_destructive(),
+            ConfirmationPolicy::Never => false,
+        }
+    }
+
+    /// Check a result of this tool against its output schema
+    ///
+    /// Results of tools without an output schema, and error results, always
+    /// pass. Otherwise the result must carry structured content matching
+    /// the schema.
+    #[cfg(feature = "validation")]
+    pub fn validate_output(&self, result: &CallToolResult) -> Result<(), McpError> {
+        let Some(schema) = &self.output_schema else {
+            return Ok(());
+        };
+        if result.is_error == Some(true) {
+            return Ok(());
+        }
+        let structured = result.structured_content.as_ref().ok_or_else(|| {
+            McpError::internal_error(format!(
+                "Tool {} declares an output schema but returned no structured content",
+                self.name
+            ))
+        })?;
+        let violations = crate::validation::schema_violations(schema, structured)?;
+        if violations.is_empty() {
+            return Ok(());
+        }
+        Err(McpError::with_data(
+            ErrorCode::InternalError,
+            format!(
+                "Structured content of tool {} does not match its output schema",
+                self.name
+            ),
+            serde_json::json!({ "tool": self.name, "errors": violations }),
+        ))
+    }
+
     /// Add a parameter to the tool's input schema
     pub fn with_parameter(
         mut self,
@@ -155,37 +404,161 @@ impl Tool {
     }
 }
 
-impl ToolResultContent {
-    /// Create text content
-    pub fn text(text: impl Into<String>) -> Self {
-        Self::Text { text: text.into() }
+impl CallToolResult {
+    /// Create a successful result
+    pub fn new(content: Vec<ContentBlock>) -> Self {
+        Self {
+            content,
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        }
     }
 
-    /// Create image content
-    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
-        Self::Image {
-            data: data.into(),
-            mime_type: mime_type.into(),
+    /// Create a result reporting that the tool failed
+    pub fn error(content: Vec<ContentBlock>) -> Self {
+        Self {
+            is_error: Some(true),
+            ..Self::new(content)
         }
     }
 
-    /// Create resource reference
-    pub fn resource(uri: impl Into<String>) -> Self {
-        Self::Resource {
-            resource: uri.into(),
+    /// Create a successful result carrying `value` as structured content
+    ///
+    /// The serialized JSON is also included as a text block for clients
+    /// that do not read structured content. Fails unless `value` serializes
+    /// to a JSON object.
+    pub fn structured<T: Serialize>(value: &T) -> Result<Self, McpError> {
+        let structured = serde_json::to_value(value)
+            .map_err(|e| McpError::internal_error(format!("Invalid structured content: {}", e)))?;
+        if !structured.is_object() {
+            return Err(McpError::invalid_params(format!(
+                "Structured content must be a JSON object, got {}",
+                structured
+            )));
         }
+        Ok(Self {
+            content: vec![ContentBlock::text(structured.to_string())],
+            structured_content: Some(structured),
+            ..Self::new(Vec::new())
+        })
+    }
+
+    /// Replace content `version` does not allow with text placeholders
+    pub fn downgrade_for(mut self, version: ProtocolVersion) -> Self {
+        self.content = self
+            .content
+            .into_iter()
+            .map(|content| content.downgrade_for(version))
+            .collect();
+        self
+    }
+
+    /// Drop content not meant for `role`
+    pub fn retain_for(&mut self, role: Role) {
+        self.content.retain(|content| content.is_for(role));
+    }
+
+    /// Decode the structured content, if any
+    pub fn structured_as<T: DeserializeOwned>(&self) -> Result<Option<T>, McpError> {
+        self.structured_content
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| McpError::invalid_params(format!("Invalid structured content: {}", e)))
     }
 }
 
+impl ToolAnnotations {
+    /// Create annotations with every hint absent
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the display title
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the read-only hint
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only_hint = Some(read_only);
+        self
+    }
+
+    /// Set the destructive hint
+    pub fn destructive(mut self, destructive: bool) -> Self {
+        self.destructive_hint = Some(destructive);
+        self
+    }
+
+    /// Set the idempotent hint
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent_hint = Some(idempotent);
+        self
+    }
+
+    /// Set the open-world hint
+    pub fn open_world(mut self, open_world: bool) -> Self {
+        self.open_world_hint = Some(open_world);
+        self
+    }
+
+    /// Whether the tool leaves its environment unmodified
+    pub fn is_read_only(&self) -> bool {
+        self.read_only_hint.unwrap_or(false)
+    }
+
+    /// Whether the tool may perform destructive updates
+    ///
+    /// Always `false` for read-only tools.
+    pub fn is_destructive(&self) -> bool {
+        !self.is_read_only() && self.destructive_hint.unwrap_or(true)
+    }
+
+    /// Whether repeated calls with the same arguments have no further effect
+    ///
+    /// Always `true` for read-only tools.
+    pub fn is_idempotent(&self) -> bool {
+        self.is_read_only() || self.idempotent_hint.unwrap_or(false)
+    }
+
+    /// Whether the tool interacts with external entities
+    pub fn is_open_world(&self) -> bool {
+        self.open_world_hint.unwrap_or(true)
+    }
+}
+
+impl McpRequest for ListToolsRequest {
+    const METHOD: &'static str = "tools/list";
+    type Result = ListToolsResult;
+}
+
+impl McpRequest for CallToolRequest {
+    const METHOD: &'static str = "tools/call";
+    type Result = CallToolResult;
+}
+
+impl McpNotification for ToolListChangedNotification {
+    const METHOD: &'static str = "notifications/tools/list_changed";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::annotations::Annotations;
+    use crate::errors::ErrorCode;
+    use crate::resources::ResourceContents;
     use serde_json::json;
 
     #[test]
     fn test_tool_creation() {
-        let tool = Tool::new("calculate", "Perform mathematical calculations")
-            .with_parameter("expression", "Mathematical expression to evaluate", true);
+        let tool = Tool::new("calculate", "Perform mathematical calculations").with_parameter(
+            "expression",
+            "Mathematical expression to evaluate",
+            true,
+        );
 
         assert_eq!(tool.name, "calculate");
         assert!(tool.description.is_some());
@@ -196,6 +569,7 @@ mod tests {
     fn test_tool_serialization() {
         let tool = Tool {
             name: "test_tool".to_string(),
+            title: None,
             description: Some("A test tool".to_string()),
             input_schema: ToolInputSchema {
                 type_: "object".to_string(),
@@ -203,6 +577,9 @@ mod tests {
                 required: Some(vec!["param".to_string()]),
                 additional: None,
             },
+            output_schema: None,
+            annotations: None,
+            icons: Vec::new(),
         };
 
         let json = serde_json::to_string(&tool).unwrap();
@@ -210,11 +587,134 @@ mod tests {
         assert_eq!(tool, deserialized);
     }
 
+    #[test]
+    fn test_tool_annotations() {
+        let tool = Tool::new("delete_file", "Delete a file")
+            .with_title("Delete File")
+            .with_annotations(ToolAnnotations::new().idempotent(true));
+        let json = serde_json::to_value(&tool).unwrap();
+        assert_eq!(json["title"], "Delete File");
+        assert_eq!(json["annotations"], json!({"idempotentHint": true}));
+
+        let hints = tool.hints();
+        assert!(!hints.is_read_only());
+        assert!(hints.is_destructive());
+        assert!(hints.is_idempotent());
+        assert!(hints.is_open_world());
+
+        let read_only = ToolAnnotations::new().read_only(true).destructive(true);
+        assert!(!read_only.is_destructive());
+        assert!(read_only.is_idempotent());
+    }
+
+    #[test]
+    fn test_requires_confirmation() {
+        let unannotated = Tool::new("run", "Run a command");
+        let search = Tool::new("search", "Search files")
+            .with_annotations(ToolAnnotations::new().read_only(true));
+        let append = Tool::new("append", "Append to a log")
+            .with_annotations(ToolAnnotations::new().destructive(false));
+
+        assert!(unannotated.requires_confirmation(ConfirmationPolicy::Destructive));
+        assert!(unannotated.requires_confirmation(ConfirmationPolicy::UnlessReadOnly));
+        assert!(!search.requires_confirmation(ConfirmationPolicy::UnlessReadOnly));
+        assert!(search.requires_confirmation(ConfirmationPolicy::Always));
+        assert!(append.requires_confirmation(ConfirmationPolicy::UnlessReadOnly));
+        assert!(!append.requires_confirmation(ConfirmationPolicy::Destructive));
+        assert!(!unannotated.requires_confirmation(ConfirmationPolicy::Never));
+    }
+
+    #[test]
+    fn test_structured_result() {
+        let result = CallToolResult::structured(&json!({"temperature": 22.5})).unwrap();
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            json!({
+                "content": [{"type": "text", "text": "{\"temperature\":22.5}"}],
+                "structuredContent": {"temperature": 22.5}
+            })
+        );
+        let value: Value = result.structured_as().unwrap().unwrap();
+        assert_eq!(value["temperature"], 22.5);
+        assert_eq!(
+            CallToolResult::new(Vec::new())
+                .structured_as::<Value>()
+                .unwrap(),
+            None
+        );
+
+        let error = CallToolResult::structured(&vec![1, 2, 3]).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams);
+        assert_eq!(
+            error.message,
+            "Structured content must be a JSON object, got [1,2,3]"
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_validate_output() {
+        let tool = Tool::new("weather", "Get the weather").with_output_schema(json!({
+            "type": "object",
+            "properties": {"temperature": {"type": "number"}},
+            "required": ["temperature"]
+        }));
+
+        let valid = CallToolResult::structured(&json!({"temperature": 22.5})).unwrap();
+        assert!(tool.validate_output(&valid).is_ok());
+
+        let invalid = CallToolResult::structured(&json!({"temperature": "warm"})).unwrap();
+        let error = tool.validate_output(&invalid).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InternalError);
+
+        let missing = CallToolResult::new(vec![ContentBlock::text("22.5")]);
+        assert!(tool.validate_output(&missing).is_err());
+        let failed = CallToolResult::error(vec![ContentBlock::text("offline")]);
+        assert!(tool.validate_output(&failed).is_ok());
+    }
+
+    #[test]
+    fn test_audio_content() {
+        let audio = ContentBlock::audio_from_bytes(b"RIFF", "audio/wav");
+        assert!(audio.is_supported_by(ProtocolVersion::V2025_03_26));
+        assert!(!audio.is_supported_by(ProtocolVersion::V2024_11_05));
+
+        let result = CallToolResult::new(vec![ContentBlock::text("Recorded"), audio]);
+        let current = result.clone().downgrade_for(ProtocolVersion::LATEST);
+        assert_eq!(current, result);
+
+        let downgraded = result.downgrade_for(ProtocolVersion::V2024_11_05);
+        assert_eq!(
+            downgraded.content[1],
+            ContentBlock::text(
+                "[audio/wav audio omitted: not supported by protocol version 2024-11-05]"
+            )
+        );
+    }
+
+    #[test]
+    fn test_call_tool_result_retain_for() {
+        let result = CallToolResult::new(vec![
+            ContentBlock::text("summary"),
+            ContentBlock::text("raw output")
+                .with_annotations(Annotations::new().for_audience([Role::Assistant])),
+        ]);
+        let mut for_user = result.clone();
+        for_user.retain_for(Role::User);
+        assert_eq!(for_user.content, vec![ContentBlock::text("summary")]);
+        let mut for_assistant = result;
+        for_assistant.retain_for(Role::Assistant);
+        assert_eq!(for_assistant.content.len(), 2);
+    }
+
     #[test]
     fn test_tool_result_content() {
-        let text = ToolResultContent::text("Hello, world!");
-        let image = ToolResultContent::image("base64data", "image/png");
-        let resource = ToolResultContent::resource("file:///path/to/file.txt");
+        let text = ContentBlock::text("Hello, world!");
+        let image = ContentBlock::image("base64data", "image/png");
+        let resource = ContentBlock::resource(ResourceContents::text(
+            "file:///path/to/file.txt",
+            "file contents",
+        ));
 
         // Test serialization
         let text_json = serde_json::to_string(&text).unwrap();
@@ -222,8 +722,35 @@ mod tests {
         let resource_json = serde_json::to_string(&resource).unwrap();
 
         // Test deserialization
-        let _: ToolResultContent = serde_json::from_str(&text_json).unwrap();
-        let _: ToolResultContent = serde_json::from_str(&image_json).unwrap();
-        let _: ToolResultContent = serde_json::from_str(&resource_json).unwrap();
+        let _: ContentBlock = serde_json::from_str(&text_json).unwrap();
+        let _: ContentBlock = serde_json::from_str(&image_json).unwrap();
+        let _: ContentBlock = serde_json::from_str(&resource_json).unwrap();
+    }
+
+    #[test]
+    fn test_call_tool_typed_request() {
+        let request = CallToolRequest {
+            name: "calculate".to_string(),
+            arguments: Some(json!({"expression": "2 + 2"})),
+            meta: None,
+        };
+        let json_rpc = request
+            .clone()
+            .into_request(crate::protocol::RequestId::Number(1))
+            .unwrap();
+        assert_eq!(json_rpc.method, "tools/call");
+        assert_eq!(CallToolRequest::from_request(&json_rpc).unwrap(), request);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_tool_list_changed_round_trip() {
+        let notification = ToolListChangedNotification::default();
+        let json_rpc = notification.clone().into_notification().unwrap();
+        assert_eq!(json_rpc.method, "notifications/tools/list_changed");
+        assert_eq!(json_rpc.params, None);
+        assert_eq!(
+            ToolListChangedNotification::from_notification(&json_rpc).unwrap(),
+            notification
+        );
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! JSON Schema validation, available with the `validation` feature.
+
+use crate::errors::McpError;
+use jsonschema::JSONSchema;
+use serde_json::Value;
+
+/// Validate `instance` against `schema`
+///
+/// Returns one message per violation, each naming the JSON pointer of the
+/// offending value. Fails if `schema` is not a valid JSON Schema.
+pub(crate) fn schema_violations(schema: &Value, instance: &Value) -> Result<Vec<String>, McpError> {
+    let compiled = JSONSchema::compile(schema)
+        .map_err(|e| McpError::invalid_params(format!("Invalid JSON schema: {}", e)))?;
+    let violations = match compiled.validate(instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|error| match error.instance_path.to_string() {
+                path if path.is_empty() => error.to_string(),
+                path => format!("{} (at {})", error, path),
+            })
+            .collect(),
+    };
+    Ok(violations)
+}
@@ -0,0 +1,221 @@
+// Copyright (c) 2025 MCP Rust Contributors
+// SPDX-License-Identifier: MIT
+
+//! MCP protocol revisions and version negotiation.
+//!
+//! `InitializeParams.protocol_version` and `InitializeResult.protocol_version`
+//! stay plain strings on the wire so unknown revisions can still be read;
+//! this module turns them into [`ProtocolVersion`] values and implements the
+//! negotiation rules from the lifecycle section of the specification.
+
+use crate::errors::{ErrorCode, McpError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
+use std::fmt;
+use std::str::FromStr;
+
+/// Dated revision of the MCP specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProtocolVersion {
+    /// 2024-11-05
+    V2024_11_05,
+    /// 2025-03-26
+    V2025_03_26,
+    /// 2025-06-18
+    V2025_06_18,
+    /// 2025-11-25
+    V2025_11_25,
+}
+
+impl ProtocolVersion {
+    /// Every known revision, oldest first
+    pub const ALL: &'static [ProtocolVersion] = &[
+        Self::V2024_11_05,
+        Self::V2025_03_26,
+        Self::V2025_06_18,
+        Self::V2025_11_25,
+    ];
+
+    /// Most recent known revision
+    pub const LATEST: ProtocolVersion = Self::V2025_11_25;
+
+    /// Revision string as sent on the wire
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V2024_11_05 => "2024-11-05",
+            Self::V2025_03_26 => "2025-03-26",
+            Self::V2025_06_18 => "2025-06-18",
+            Self::V2025_11_25 => "2025-11-25",
+        }
+    }
+
+    /// Whether this revision allows audio content
+    pub fn supports_audio(&self) -> bool {
+        *self >= Self::V2025_03_26
+    }
+
+    /// Whether this revision allows `resource_link` content
+    pub fn supports_resource_links(&self) -> bool {
+        *self >= Self::V2025_06_18
+    }
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ProtocolVersion {
+    type Err = McpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|version| version.as_str() == s)
+            .ok_or_else(|| unsupported_version(s, Self::ALL))
+    }
+}
+
+impl Serialize for ProtocolVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let version = String::deserialize(deserializer)?;
+        version
+            .parse()
+            .map_err(|e: McpError| serde::de::Error::custom(e.message))
+    }
+}
+
+/// Choose the version a server answers `initialize` with
+///
+/// If the client's requested version is supported it is echoed back;
+/// otherwise the latest supported version is offered instead, and the
+/// client decides whether it can continue. Only a server supporting no
+/// versions at all fails, with the supported list in the error data.
+pub fn negotiate(
+    client_requested: &str,
+    server_supported: &[ProtocolVersion],
+) -> Result<ProtocolVersion, McpError> {
+    if let Some(version) = server_supported
+        .iter()
+        .copied()
+        .find(|version| version.as_str() == client_requested)
+    {
+        return Ok(version);
+    }
+    server_supported
+        .iter()
+        .copied()
+        .max()
+        .ok_or_else(|| unsupported_version(client_requested, server_supported))
+}
+
+/// Check the version a server answered with, as the client
+///
+/// Fails if the client does not support it, in which case the client
+/// should disconnect.
+pub fn accept_server_version(
+    server_version: &str,
+    client_supported: &[ProtocolVersion],
+) -> Result<ProtocolVersion, McpError> {
+    client_supported
+        .iter()
+        .copied()
+        .find(|version| version.as_str() == server_version)
+        .ok_or_else(|| unsupported_version(server_version, client_supported))
+}
+
+/// Build the spec's "Unsupported protocol version" error
+fn unsupported_version(requested: &str, supported: &[ProtocolVersion]) -> McpError {
+    let supported: Vec<&str> = supported.iter().map(ProtocolVersion::as_str).collect();
+    McpError::with_data(
+        ErrorCode::InvalidParams,
+        "Unsupported protocol version",
+        json!({
+            "supported": supported,
+            "requested": requested,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_parsing_and_ordering() {
+        let version: ProtocolVersion = "2025-03-26".parse().unwrap();
+        assert_eq!(version, ProtocolVersion::V2025_03_26);
+        assert!(ProtocolVersion::V2024_11_05 < ProtocolVersion::V2025_06_18);
+        assert_eq!(ProtocolVersion::ALL.last(), Some(&ProtocolVersion::LATEST));
+
+        let json = serde_json::to_string(&ProtocolVersion::V2025_06_18).unwrap();
+        assert_eq!(json, "\"2025-06-18\"");
+        assert!(serde_json::from_str::<ProtocolVersion>("\"1999-01-01\"").is_err());
+    }
+
+    #[test]
+    fn test_supports_audio() {
+        assert!(!ProtocolVersion::V2024_11_05.supports_audio());
+        assert!(ProtocolVersion::V2025_03_26.supports_audio());
+        assert!(ProtocolVersion::LATEST.supports_audio());
+    }
+
+    #[test]
+    fn test_supports_resource_links() {
+        assert!(!ProtocolVersion::V2025_03_26.supports_resource_links());
+        assert!(ProtocolVersion::V2025_06_18.supports_resource_links());
+    }
+
+    #[test]
+    fn test_negotiate() {
+        let supported = [ProtocolVersion::V2024_11_05, ProtocolVersion::V2025_03_26];
+        assert_eq!(
+            negotiate("2024-11-05", &supported).unwrap(),
+            ProtocolVersion::V2024_11_05
+        );
+        assert_eq!(
+            negotiate("2099-01-01", &supported).unwrap(),
+            ProtocolVersion::V2025_03_26
+        );
+        assert_eq!(
+            negotiate("2025-06-18", &supported).unwrap(),
+            ProtocolVersion::V2025_03_26
+        );
+
+        let error = negotiate("2025-06-18", &[]).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams);
+    }
+
+    #[test]
+    fn test_accept_server_version() {
+        let supported = [ProtocolVersion::V2025_06_18];
+        assert!(accept_server_version("2025-06-18", &supported).is_ok());
+
+        let error = accept_server_version("2024-11-05", &supported).unwrap_err();
+        assert_eq!(error.message, "Unsupported protocol version");
+        assert_eq!(
+            error.data,
+            Some(json!({"supported": ["2025-06-18"], "requested": "2024-11-05"}))
+        );
+    }
+}